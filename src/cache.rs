@@ -0,0 +1,189 @@
+//! A small cache of fuzzy matching results, see [`MonthParser::cache`](crate::MonthParser::cache).
+
+use std::{collections::VecDeque, sync::Mutex};
+
+use crate::{MonthMatch, TieBreak};
+
+/// The parser settings that fuzzy matching depends on, besides the metric
+///
+/// Results are cached together with these, so that a parser that is
+/// reconfigured after parsing never returns the result of its old settings.
+/// A new metric gets a new cache altogether.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Settings {
+    /// The bits of the similarity threshold, which can't be compared with
+    /// `Eq` as a float
+    pub threshold: u64,
+    pub phonetic: bool,
+    pub tie_break: TieBreak,
+}
+
+/// A normalized input with the settings it was matched with
+type Key = (String, Settings);
+
+/// A cache key and its fuzzy matching result
+type Entry = (Key, Option<MonthMatch>);
+
+/// A bounded, least-recently-used cache of fuzzy matching results, keyed on
+/// the normalized input and the parser settings
+///
+/// Entries are kept in a list with the most recently used first, which is
+/// faster than hashing for the small capacities the cache is meant for. The
+/// list is behind a mutex, so that a parser can be shared between threads.
+#[derive(Debug)]
+pub struct FuzzyCache {
+    capacity: usize,
+    entries: Mutex<VecDeque<Entry>>,
+}
+
+impl FuzzyCache {
+    /// Create an empty cache of at most `capacity` entries
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// The maximum number of entries
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The cached result for `input`, or the result of `compute`, which is
+    /// then cached
+    ///
+    /// The lock isn't held while computing, so concurrent misses of the same
+    /// input may both compute it.
+    pub fn get_or_insert_with(
+        &self,
+        input: &str,
+        settings: Settings,
+        compute: impl FnOnce() -> Option<MonthMatch>,
+    ) -> Option<MonthMatch> {
+        if let Some((_, result)) = self.get(input, settings) {
+            return result;
+        }
+        let result = compute();
+        self.insert(input, settings, result.clone());
+        result
+    }
+
+    /// The cached entry for `input`, which becomes the most recently used
+    fn get(&self, input: &str, settings: Settings) -> Option<Entry> {
+        let mut entries = self.lock();
+        let position = entries
+            .iter()
+            .position(|((key, key_settings), _)| key == input && *key_settings == settings)?;
+        let entry = entries.remove(position)?;
+        entries.push_front(entry.clone());
+        drop(entries);
+        Some(entry)
+    }
+
+    /// Cache the result for `input`, dropping the least recently used one if
+    /// the cache is full
+    fn insert(&self, input: &str, settings: Settings, result: Option<MonthMatch>) {
+        let mut entries = self.lock();
+        // Another thread may have cached it since the lookup
+        entries.retain(|((key, key_settings), _)| key != input || *key_settings != settings);
+        entries.push_front(((input.to_string(), settings), result));
+        entries.truncate(self.capacity);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, VecDeque<Entry>> {
+        // The entries are always consistent, even if another thread panicked
+        self.entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// A clone starts out empty, so that differently configured clones of a
+/// parser never share results
+impl Clone for FuzzyCache {
+    fn clone(&self) -> Self {
+        Self::new(self.capacity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Month;
+
+    const SETTINGS: Settings = Settings {
+        threshold: 0,
+        phonetic: false,
+        tie_break: TieBreak::Latest,
+    };
+
+    fn result(month: Month) -> Option<MonthMatch> {
+        crate::parse_month_detailed(month.name())
+            .ok()
+            .map(|result| MonthMatch {
+                normalized_input: String::new(),
+                ..result
+            })
+    }
+
+    #[test]
+    fn test_hit_skips_compute() {
+        let cache = FuzzyCache::new(2);
+        assert_eq!(
+            cache.get_or_insert_with("marh", SETTINGS, || result(Month::March)),
+            result(Month::March)
+        );
+        assert_eq!(
+            cache.get_or_insert_with("marh", SETTINGS, || unreachable!()),
+            result(Month::March)
+        );
+        assert_eq!(cache.get_or_insert_with("xyz", SETTINGS, || None), None);
+        assert_eq!(
+            cache.get_or_insert_with("xyz", SETTINGS, || unreachable!()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_other_settings_miss() {
+        let cache = FuzzyCache::new(2);
+        cache.get_or_insert_with("marh", SETTINGS, || result(Month::March));
+        let strict = Settings {
+            threshold: 1,
+            ..SETTINGS
+        };
+        assert_eq!(cache.get_or_insert_with("marh", strict, || None), None);
+        assert_eq!(
+            cache.get_or_insert_with("marh", SETTINGS, || unreachable!()),
+            result(Month::March)
+        );
+    }
+
+    #[test]
+    fn test_least_recently_used_is_evicted() {
+        let cache = FuzzyCache::new(2);
+        cache.get_or_insert_with("a", SETTINGS, || result(Month::April));
+        cache.get_or_insert_with("b", SETTINGS, || result(Month::May));
+        cache.get_or_insert_with("a", SETTINGS, || unreachable!()); // now used more recently than "b"
+        cache.get_or_insert_with("c", SETTINGS, || result(Month::June));
+        assert_eq!(
+            cache.get_or_insert_with("a", SETTINGS, || unreachable!()),
+            result(Month::April)
+        );
+        assert_eq!(cache.get_or_insert_with("b", SETTINGS, || None), None);
+    }
+
+    #[test]
+    fn test_clone_is_empty() {
+        let cache = FuzzyCache::new(2);
+        cache.get_or_insert_with("marh", SETTINGS, || result(Month::March));
+        let clone = cache.clone();
+        assert_eq!(clone.get_or_insert_with("marh", SETTINGS, || None), None);
+        assert_eq!(
+            cache.get_or_insert_with("marh", SETTINGS, || unreachable!()),
+            result(Month::March)
+        );
+    }
+}
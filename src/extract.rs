@@ -0,0 +1,142 @@
+//! Extraction of months mentioned in free text.
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::{
+    is_abbreviation, match_number_word, normalize, parse_month_exact, Month, ABBREVIATIONS,
+};
+
+/// Find all months mentioned in a block of text
+///
+/// The text is split into words (runs of alphabetic characters) and every
+/// word is matched with [`parse_month_exact`]. Fuzzy matching is not used, as
+/// it would turn many ordinary words into months. Numbers are ignored for the
+/// same reason, since `"3 apples"` should not yield March, and so are spelled
+/// out numbers (`"the first time"`). Of the abbreviations, only the English
+/// ones of at least three letters (`"Dec"`) are recognized, since many
+/// localized abbreviations are ordinary words, like the Italian `"ago"` and
+/// `"set"` or the Polish `"się"`.
+///
+/// Returns the months in the order they appear, together with the byte range
+/// of the matching word in `text`.
+///
+/// Note that some English words are also month names (`"may"`, `"march"`),
+/// and that scripts without spaces between words (e.g. Chinese) are not split.
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::{extract_months, Month};
+///
+/// let text = "Our busy months are June, July and August.";
+/// let months: Vec<Month> = extract_months(text).into_iter().map(|(m, _)| m).collect();
+/// assert_eq!(months, [Month::June, Month::July, Month::August]);
+/// ```
+#[must_use]
+pub fn extract_months(text: &str) -> Vec<(Month, Range<usize>)> {
+    words(text)
+        .filter_map(|span| match_word(&text[span.clone()]).map(|month| (month, span)))
+        .collect()
+}
+
+/// The month spelled out by a single word, if it is not likely to be an
+/// ordinary word instead
+fn match_word(word: &str) -> Option<Month> {
+    if match_number_word(word).is_some() {
+        return None;
+    }
+    let month = parse_month_exact(word).ok()?;
+    let english_abbreviation = ABBREVIATIONS.iter().any(|(abbreviation, _)| {
+        abbreviation.len() >= 3 && word.eq_ignore_ascii_case(abbreviation)
+    });
+    (english_abbreviation || !is_abbreviation(word)).then_some(month)
+}
+
+/// Byte ranges of the words in `text`
+///
+/// A word is a run of letters, including the combining marks that belong to
+/// them (like the virama in `"मार्च"`), which are not alphabetic themselves.
+fn words(text: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    let mut chars = text.char_indices().peekable();
+    core::iter::from_fn(move || {
+        let (start, _) = chars.find(|(_, c)| c.is_alphabetic())?;
+        let mut end = text.len();
+        while let Some((i, c)) = chars.peek() {
+            if !c.is_alphabetic() && !normalize::is_mark(*c) {
+                end = *i;
+                break;
+            }
+            chars.next();
+        }
+        Some(start..end)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_months() {
+        let text = "Our busy months are June, July and August. \
+                    We close in Dec. and reopen in January, usually by the 3rd.";
+        let months = extract_months(text);
+        assert_eq!(
+            months,
+            [
+                (Month::June, 20..24),
+                (Month::July, 26..30),
+                (Month::August, 35..41),
+                (Month::December, 55..58),
+                (Month::January, 74..81),
+            ]
+        );
+        for (month, span) in months {
+            assert_eq!(crate::parse_month(&text[span]).unwrap(), month);
+        }
+    }
+
+    #[cfg(all(feature = "lang-de", feature = "lang-fr"))]
+    #[test]
+    fn test_extract_months_non_ascii() {
+        let text = "Ferien im März und im Août";
+        assert_eq!(
+            extract_months(text),
+            [(Month::March, 10..15), (Month::August, 23..28)]
+        );
+    }
+
+    #[cfg(feature = "lang-hi")]
+    #[test]
+    fn test_extract_months_hindi() {
+        let text = "मार्च और दिसम्बर";
+        assert_eq!(
+            extract_months(text),
+            [(Month::March, 0..15), (Month::December, 23..44)]
+        );
+    }
+
+    #[cfg(feature = "lang-de")]
+    #[test]
+    fn test_extract_months_decomposed() {
+        // "März" with the umlaut as a combining diaeresis
+        let text = "im Ma\u{308}rz";
+        assert_eq!(extract_months(text), [(Month::March, 3..9)]);
+    }
+
+    #[test]
+    fn test_extract_months_ignores_typos_and_numbers() {
+        assert!(extract_months("Janurary has 31 days, 3 of them holidays").is_empty());
+        assert!(extract_months("").is_empty());
+        assert!(extract_months("The first time, the second time").is_empty());
+    }
+
+    #[test]
+    fn test_extract_months_ignores_localized_abbreviations() {
+        assert!(extract_months("two years ago").is_empty());
+        assert!(extract_months("we set the table").is_empty());
+        assert!(extract_months("coś się stało").is_empty());
+        assert!(extract_months("Ja, ich komme").is_empty());
+    }
+}
@@ -0,0 +1,25 @@
+//! Arabic month names.
+
+use crate::Month;
+
+/// Arabic month names
+///
+/// Input is matched without tashkeel and tatweel, with presentation forms
+/// replaced by the canonical letters and hamza on alef ignored, so
+/// `"يَنَايِر"` and `"ابريل"` are found as well.
+pub const VARIANTS: &[(&str, Month)] = &[
+    ("يناير", Month::January),
+    ("فبراير", Month::February),
+    ("مارس", Month::March),
+    ("أبريل", Month::April),
+    ("مايو", Month::May),
+    ("يونيو", Month::June),
+    ("يوليو", Month::July),
+    ("أغسطس", Month::August),
+    ("سبتمبر", Month::September),
+    ("أكتوبر", Month::October),
+    ("نوفمبر", Month::November),
+    ("ديسمبر", Month::December),
+];
+
+pub const ABBREVIATIONS: &[(&str, Month)] = &[];
@@ -0,0 +1,37 @@
+//! Czech month names.
+
+use crate::Month;
+
+/// Czech month names
+///
+/// Unlike most European languages, Czech does not use the Latin month names
+/// (`"leden"` is January), so they have little in common with Slovak. Dates
+/// use the genitive (`"1. ledna"`), which is listed as well. `"září"`
+/// (September) is the same in both cases.
+pub const VARIANTS: &[(&str, Month)] = &[
+    ("leden", Month::January),
+    ("ledna", Month::January), // genitive
+    ("únor", Month::February),
+    ("února", Month::February), // genitive
+    ("březen", Month::March),
+    ("března", Month::March), // genitive
+    ("duben", Month::April),
+    ("dubna", Month::April), // genitive
+    ("květen", Month::May),
+    ("května", Month::May), // genitive
+    ("červen", Month::June),
+    ("června", Month::June), // genitive
+    ("červenec", Month::July),
+    ("července", Month::July), // genitive
+    ("srpen", Month::August),
+    ("srpna", Month::August), // genitive
+    ("září", Month::September),
+    ("říjen", Month::October),
+    ("října", Month::October), // genitive
+    ("listopad", Month::November),
+    ("listopadu", Month::November), // genitive
+    ("prosinec", Month::December),
+    ("prosince", Month::December), // genitive
+];
+
+pub const ABBREVIATIONS: &[(&str, Month)] = &[];
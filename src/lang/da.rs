@@ -0,0 +1,35 @@
+//! Danish month names.
+
+use crate::Month;
+
+/// Danish month names
+pub const VARIANTS: &[(&str, Month)] = &[
+    ("januar", Month::January),
+    ("februar", Month::February),
+    ("marts", Month::March),
+    ("april", Month::April),
+    ("maj", Month::May),
+    ("juni", Month::June),
+    ("juli", Month::July),
+    ("august", Month::August),
+    ("september", Month::September),
+    ("oktober", Month::October),
+    ("november", Month::November),
+    ("december", Month::December),
+];
+
+/// Abbreviated Danish month names
+pub const ABBREVIATIONS: &[(&str, Month)] = &[
+    ("jan", Month::January),
+    ("feb", Month::February),
+    ("mar", Month::March),
+    ("apr", Month::April),
+    ("maj", Month::May),
+    ("jun", Month::June),
+    ("jul", Month::July),
+    ("aug", Month::August),
+    ("sep", Month::September),
+    ("okt", Month::October),
+    ("nov", Month::November),
+    ("dec", Month::December),
+];
@@ -0,0 +1,44 @@
+//! German month names.
+
+use crate::Month;
+
+/// German month names
+///
+/// Austrian German names January and February differently; they are listed
+/// after the standard names, which [`Month::localized_name`] returns. Swiss
+/// German uses the standard names.
+pub const VARIANTS: &[(&str, Month)] = &[
+    ("januar", Month::January),
+    ("februar", Month::February),
+    ("märz", Month::March),
+    ("april", Month::April),
+    ("mai", Month::May),
+    ("juni", Month::June),
+    ("juli", Month::July),
+    ("august", Month::August),
+    ("september", Month::September),
+    ("oktober", Month::October),
+    ("november", Month::November),
+    ("dezember", Month::December),
+    ("jänner", Month::January), // Austrian
+    ("feber", Month::February), // Austrian
+];
+
+/// Abbreviated German month names
+pub const ABBREVIATIONS: &[(&str, Month)] = &[
+    ("jan", Month::January),
+    ("jän", Month::January),
+    ("feb", Month::February),
+    ("mrz", Month::March),
+    ("mär", Month::March),
+    ("apr", Month::April),
+    ("mai", Month::May),
+    ("jun", Month::June),
+    ("jul", Month::July),
+    ("aug", Month::August),
+    ("sept", Month::September),
+    ("sep", Month::September),
+    ("okt", Month::October),
+    ("nov", Month::November),
+    ("dez", Month::December),
+];
@@ -0,0 +1,36 @@
+//! Spanish month names.
+
+use crate::Month;
+
+/// Spanish month names
+pub const VARIANTS: &[(&str, Month)] = &[
+    ("enero", Month::January),
+    ("febrero", Month::February),
+    ("marzo", Month::March),
+    ("abril", Month::April),
+    ("mayo", Month::May),
+    ("junio", Month::June),
+    ("julio", Month::July),
+    ("agosto", Month::August),
+    ("septiembre", Month::September),
+    ("octubre", Month::October),
+    ("noviembre", Month::November),
+    ("diciembre", Month::December),
+];
+
+/// Abbreviated Spanish month names
+pub const ABBREVIATIONS: &[(&str, Month)] = &[
+    ("ene", Month::January),
+    ("feb", Month::February),
+    ("mar", Month::March),
+    ("abr", Month::April),
+    ("may", Month::May),
+    ("jun", Month::June),
+    ("jul", Month::July),
+    ("ago", Month::August),
+    ("sept", Month::September),
+    ("sep", Month::September),
+    ("oct", Month::October),
+    ("nov", Month::November),
+    ("dic", Month::December),
+];
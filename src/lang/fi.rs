@@ -0,0 +1,74 @@
+//! Finnish month names.
+
+use crate::Month;
+
+/// Finnish month names
+///
+/// Every name ends in `-kuu` ("moon"). Besides the nominative, the case
+/// forms that are common in dates are listed: the partitive
+/// (`"1. tammikuuta"`), the inessive (`"tammikuussa"`, "in January") and the
+/// illative (`"tammikuuhun asti"`, "until January").
+pub const VARIANTS: &[(&str, Month)] = &[
+    ("tammikuu", Month::January),
+    ("tammikuuta", Month::January),  // partitive
+    ("tammikuussa", Month::January), // inessive
+    ("tammikuuhun", Month::January), // illative
+    ("helmikuu", Month::February),
+    ("helmikuuta", Month::February),  // partitive
+    ("helmikuussa", Month::February), // inessive
+    ("helmikuuhun", Month::February), // illative
+    ("maaliskuu", Month::March),
+    ("maaliskuuta", Month::March),  // partitive
+    ("maaliskuussa", Month::March), // inessive
+    ("maaliskuuhun", Month::March), // illative
+    ("huhtikuu", Month::April),
+    ("huhtikuuta", Month::April),  // partitive
+    ("huhtikuussa", Month::April), // inessive
+    ("huhtikuuhun", Month::April), // illative
+    ("toukokuu", Month::May),
+    ("toukokuuta", Month::May),  // partitive
+    ("toukokuussa", Month::May), // inessive
+    ("toukokuuhun", Month::May), // illative
+    ("kesäkuu", Month::June),
+    ("kesäkuuta", Month::June),  // partitive
+    ("kesäkuussa", Month::June), // inessive
+    ("kesäkuuhun", Month::June), // illative
+    ("heinäkuu", Month::July),
+    ("heinäkuuta", Month::July),  // partitive
+    ("heinäkuussa", Month::July), // inessive
+    ("heinäkuuhun", Month::July), // illative
+    ("elokuu", Month::August),
+    ("elokuuta", Month::August),  // partitive
+    ("elokuussa", Month::August), // inessive
+    ("elokuuhun", Month::August), // illative
+    ("syyskuu", Month::September),
+    ("syyskuuta", Month::September),  // partitive
+    ("syyskuussa", Month::September), // inessive
+    ("syyskuuhun", Month::September), // illative
+    ("lokakuu", Month::October),
+    ("lokakuuta", Month::October),  // partitive
+    ("lokakuussa", Month::October), // inessive
+    ("lokakuuhun", Month::October), // illative
+    ("marraskuu", Month::November),
+    ("marraskuuta", Month::November),  // partitive
+    ("marraskuussa", Month::November), // inessive
+    ("marraskuuhun", Month::November), // illative
+    ("joulukuu", Month::December),
+    ("joulukuuta", Month::December),  // partitive
+    ("joulukuussa", Month::December), // inessive
+    ("joulukuuhun", Month::December), // illative
+];
+
+/// Abbreviated month names
+///
+/// The names without `-kuu` are used as short forms, e.g. `"maalis"` for
+/// March. Most of these stems are common words on their own, so only the
+/// ones that aren't are listed: `"tammi"` (oak), `"helmi"` (pearl), `"kesä"`
+/// (summer), `"heinä"` (hay), `"elo"` (life), `"syys"` (autumn), `"loka"`
+/// (mud) and `"joulu"` (Christmas) are left out.
+pub const ABBREVIATIONS: &[(&str, Month)] = &[
+    ("maalis", Month::March),
+    ("huhti", Month::April),
+    ("touko", Month::May),
+    ("marras", Month::November),
+];
@@ -0,0 +1,39 @@
+//! French month names.
+
+use crate::Month;
+
+/// French month names
+pub const VARIANTS: &[(&str, Month)] = &[
+    ("janvier", Month::January),
+    ("février", Month::February),
+    ("mars", Month::March),
+    ("avril", Month::April),
+    ("mai", Month::May),
+    ("juin", Month::June),
+    ("juillet", Month::July),
+    ("août", Month::August),
+    ("septembre", Month::September),
+    ("octobre", Month::October),
+    ("novembre", Month::November),
+    ("décembre", Month::December),
+];
+
+/// Abbreviated French month names
+///
+/// `"sept"` is also the French word for seven, but only English numbers are
+/// spelled out (see `NUMBER_WORDS`), so it always means September. French
+/// number words would have to leave it out to keep it unambiguous.
+pub const ABBREVIATIONS: &[(&str, Month)] = &[
+    ("janv", Month::January),
+    ("févr", Month::February),
+    ("mars", Month::March),
+    ("avr", Month::April),
+    ("mai", Month::May),
+    ("juin", Month::June),
+    ("juil", Month::July),
+    ("août", Month::August),
+    ("sept", Month::September),
+    ("oct", Month::October),
+    ("nov", Month::November),
+    ("déc", Month::December),
+];
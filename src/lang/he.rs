@@ -0,0 +1,27 @@
+//! Hebrew month names.
+
+use crate::Month;
+
+/// Hebrew names of the Gregorian months
+///
+/// These are the transliterated Gregorian months used in everyday Hebrew,
+/// not the months of the Hebrew calendar. Input is matched regardless of
+/// final letter forms (`"מרצ"` for `"מרץ"`), vowel points and the direction
+/// marks that right-to-left text often carries.
+pub const VARIANTS: &[(&str, Month)] = &[
+    ("ינואר", Month::January),
+    ("פברואר", Month::February),
+    ("מרץ", Month::March),
+    ("מרס", Month::March),
+    ("אפריל", Month::April),
+    ("מאי", Month::May),
+    ("יוני", Month::June),
+    ("יולי", Month::July),
+    ("אוגוסט", Month::August),
+    ("ספטמבר", Month::September),
+    ("אוקטובר", Month::October),
+    ("נובמבר", Month::November),
+    ("דצמבר", Month::December),
+];
+
+pub const ABBREVIATIONS: &[(&str, Month)] = &[];
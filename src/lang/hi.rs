@@ -0,0 +1,34 @@
+//! Hindi month names.
+
+use crate::Month;
+
+/// Hindi month names
+///
+/// Hindi uses the Gregorian months, transliterated into Devanagari. Several
+/// months are commonly spelled both with an anusvara and with a half nasal
+/// consonant (`"दिसंबर"` and `"दिसम्बर"`), and `"फ़रवरी"` is written with or
+/// without the nukta, which itself can be a separate combining character or
+/// part of a precomposed letter. All of these spellings are listed.
+pub const VARIANTS: &[(&str, Month)] = &[
+    ("जनवरी", Month::January),
+    ("फ़रवरी", Month::February),       // nukta as a combining character
+    ("\u{95e}रवरी", Month::February), // precomposed nukta letter
+    ("फरवरी", Month::February),
+    ("मार्च", Month::March),
+    ("अप्रैल", Month::April),
+    ("अप्रेल", Month::April),
+    ("मई", Month::May),
+    ("जून", Month::June),
+    ("जुलाई", Month::July),
+    ("अगस्त", Month::August),
+    ("सितंबर", Month::September),
+    ("सितम्बर", Month::September),
+    ("अक्टूबर", Month::October),
+    ("अक्तूबर", Month::October),
+    ("नवंबर", Month::November),
+    ("नवम्बर", Month::November),
+    ("दिसंबर", Month::December),
+    ("दिसम्बर", Month::December),
+];
+
+pub const ABBREVIATIONS: &[(&str, Month)] = &[];
@@ -0,0 +1,37 @@
+//! Hungarian month names.
+
+use crate::Month;
+
+/// Hungarian month names
+///
+/// The names are close to the German and Latin ones, but with Hungarian
+/// spelling (`"augusztus"`, `"szeptember"`). As with all languages, they are
+/// also matched without accents, e.g. `"marcius"`.
+pub const VARIANTS: &[(&str, Month)] = &[
+    ("január", Month::January),
+    ("február", Month::February),
+    ("március", Month::March),
+    ("április", Month::April),
+    ("május", Month::May),
+    ("június", Month::June),
+    ("július", Month::July),
+    ("augusztus", Month::August),
+    ("szeptember", Month::September),
+    ("október", Month::October),
+    ("november", Month::November),
+    ("december", Month::December),
+];
+
+/// Abbreviated month names
+///
+/// Only abbreviations that differ from the English ones are listed.
+pub const ABBREVIATIONS: &[(&str, Month)] = &[
+    ("febr", Month::February),
+    ("márc", Month::March),
+    ("ápr", Month::April),
+    ("máj", Month::May),
+    ("jún", Month::June),
+    ("júl", Month::July),
+    ("szept", Month::September),
+    ("okt", Month::October),
+];
@@ -0,0 +1,40 @@
+//! Indonesian and Malay month names.
+
+use crate::Month;
+
+/// Indonesian and Malay month names
+///
+/// The two languages share most month names. Indonesian spellings come
+/// first; Malay ones that differ follow them. The Malay `"mac"` (March) is
+/// left out, as it is far more often the computer than the month in mixed
+/// text.
+pub const VARIANTS: &[(&str, Month)] = &[
+    ("januari", Month::January),
+    ("februari", Month::February),
+    ("pebruari", Month::February), // older Indonesian spelling
+    ("maret", Month::March),
+    ("april", Month::April),
+    ("mei", Month::May),
+    ("juni", Month::June),
+    ("jun", Month::June), // Malay
+    ("juli", Month::July),
+    ("julai", Month::July), // Malay
+    ("agustus", Month::August),
+    ("ogos", Month::August), // Malay
+    ("september", Month::September),
+    ("oktober", Month::October),
+    ("november", Month::November),
+    ("nopember", Month::November), // older Indonesian spelling
+    ("desember", Month::December),
+    ("disember", Month::December), // Malay
+];
+
+/// Abbreviated month names
+///
+/// Only abbreviations that differ from the English ones are listed. `"des"`
+/// (December) is left out, as it is a common French word.
+pub const ABBREVIATIONS: &[(&str, Month)] = &[
+    ("agu", Month::August),
+    ("agt", Month::August),
+    ("okt", Month::October),
+];
@@ -0,0 +1,35 @@
+//! Italian month names.
+
+use crate::Month;
+
+/// Italian month names
+pub const VARIANTS: &[(&str, Month)] = &[
+    ("gennaio", Month::January),
+    ("febbraio", Month::February),
+    ("marzo", Month::March),
+    ("aprile", Month::April),
+    ("maggio", Month::May),
+    ("giugno", Month::June),
+    ("luglio", Month::July),
+    ("agosto", Month::August),
+    ("settembre", Month::September),
+    ("ottobre", Month::October),
+    ("novembre", Month::November),
+    ("dicembre", Month::December),
+];
+
+/// Abbreviated Italian month names
+pub const ABBREVIATIONS: &[(&str, Month)] = &[
+    ("gen", Month::January),
+    ("feb", Month::February),
+    ("mar", Month::March),
+    ("apr", Month::April),
+    ("mag", Month::May),
+    ("giu", Month::June),
+    ("lug", Month::July),
+    ("ago", Month::August),
+    ("set", Month::September),
+    ("ott", Month::October),
+    ("nov", Month::November),
+    ("dic", Month::December),
+];
@@ -0,0 +1,51 @@
+//! Japanese month names.
+
+use crate::Month;
+
+/// Japanese month names
+///
+/// Months are written as `N月` with either ASCII digits (`"3月"`) or kanji
+/// numerals (`"三月"`). The kanji forms are spelled like the Chinese ones, but
+/// are listed here again, next to the classical names (`"弥生"`).
+pub const VARIANTS: &[(&str, Month)] = &[
+    ("1月", Month::January),
+    ("一月", Month::January),
+    ("睦月", Month::January), // classical
+    ("2月", Month::February),
+    ("二月", Month::February),
+    ("如月", Month::February), // classical
+    ("3月", Month::March),
+    ("三月", Month::March),
+    ("弥生", Month::March), // classical
+    ("4月", Month::April),
+    ("四月", Month::April),
+    ("卯月", Month::April), // classical
+    ("5月", Month::May),
+    ("五月", Month::May),
+    ("皐月", Month::May), // classical
+    ("6月", Month::June),
+    ("六月", Month::June),
+    ("水無月", Month::June), // classical
+    ("7月", Month::July),
+    ("七月", Month::July),
+    ("文月", Month::July), // classical
+    ("8月", Month::August),
+    ("八月", Month::August),
+    ("葉月", Month::August), // classical
+    ("9月", Month::September),
+    ("九月", Month::September),
+    ("長月", Month::September), // classical
+    ("10月", Month::October),
+    ("十月", Month::October),
+    ("神無月", Month::October), // classical
+    ("11月", Month::November),
+    ("十一月", Month::November),
+    ("霜月", Month::November), // classical
+    ("12月", Month::December),
+    ("十二月", Month::December),
+    ("師走", Month::December), // classical
+];
+
+/// The `N月` forms are as short as month names get, so there are no
+/// abbreviations.
+pub const ABBREVIATIONS: &[(&str, Month)] = &[];
@@ -0,0 +1,37 @@
+//! Korean month names.
+
+use crate::Month;
+
+/// Korean month names
+///
+/// Korean uses its own suffix `월` (`"5월"`, `"오월"`), so these never collide
+/// with the Chinese or Japanese names. Note the irregular sino-Korean
+/// spellings `"유월"` (June) and `"시월"` (October).
+pub const VARIANTS: &[(&str, Month)] = &[
+    ("1월", Month::January),
+    ("일월", Month::January),
+    ("2월", Month::February),
+    ("이월", Month::February),
+    ("3월", Month::March),
+    ("삼월", Month::March),
+    ("4월", Month::April),
+    ("사월", Month::April),
+    ("5월", Month::May),
+    ("오월", Month::May),
+    ("6월", Month::June),
+    ("유월", Month::June),
+    ("7월", Month::July),
+    ("칠월", Month::July),
+    ("8월", Month::August),
+    ("팔월", Month::August),
+    ("9월", Month::September),
+    ("구월", Month::September),
+    ("10월", Month::October),
+    ("시월", Month::October),
+    ("11월", Month::November),
+    ("십일월", Month::November),
+    ("12월", Month::December),
+    ("십이월", Month::December),
+];
+
+pub const ABBREVIATIONS: &[(&str, Month)] = &[];
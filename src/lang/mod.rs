@@ -0,0 +1,166 @@
+//! Month names in languages other than English, one module per language.
+//!
+//! Every language is behind its own `lang-xx` feature, so that builds only
+//! carry the languages they need. The `all-languages` feature (on by default)
+//! enables all of them.
+//!
+//! This is by no means complete and we should add more variants if possible.
+
+use crate::{Language, Month};
+
+#[cfg(feature = "lang-ar")]
+mod ar;
+#[cfg(feature = "lang-cs")]
+mod cs;
+#[cfg(feature = "lang-da")]
+mod da;
+#[cfg(feature = "lang-de")]
+mod de;
+#[cfg(feature = "lang-es")]
+mod es;
+#[cfg(feature = "lang-fi")]
+mod fi;
+#[cfg(feature = "lang-fr")]
+mod fr;
+#[cfg(feature = "lang-he")]
+mod he;
+#[cfg(feature = "lang-hi")]
+mod hi;
+#[cfg(feature = "lang-hu")]
+mod hu;
+#[cfg(feature = "lang-id")]
+mod id;
+#[cfg(feature = "lang-it")]
+mod it;
+#[cfg(feature = "lang-ja")]
+mod ja;
+#[cfg(feature = "lang-ko")]
+mod ko;
+#[cfg(feature = "lang-no")]
+mod no;
+#[cfg(feature = "lang-pl")]
+mod pl;
+#[cfg(feature = "lang-ru")]
+mod ru;
+#[cfg(feature = "lang-sk")]
+mod sk;
+#[cfg(feature = "lang-sv")]
+mod sv;
+#[cfg(feature = "lang-tr")]
+mod tr;
+#[cfg(feature = "lang-uk")]
+mod uk;
+#[cfg(feature = "lang-vi")]
+mod vi;
+#[cfg(feature = "lang-zh")]
+mod zh;
+
+/// Month names and abbreviations of a language
+type Table = (
+    Language,
+    &'static [(&'static str, Month)],
+    &'static [(&'static str, Month)],
+);
+
+/// The compiled-in languages
+///
+/// Within a month, the first variant of a language is its primary spelling
+/// (see [`Month::localized_name`]).
+///
+/// Many spellings are shared between languages (e.g. `"maj"` is both Polish
+/// and Swedish/Danish). Such duplicates are listed once per language on
+/// purpose, so that every language works on its own.
+///
+/// Abbreviations are prone to false positives, so they are only ever used
+/// for exact matching and never take part in fuzzy matching.
+const TABLES: &[Table] = &[
+    #[cfg(feature = "lang-es")]
+    (Language::Spanish, es::VARIANTS, es::ABBREVIATIONS),
+    #[cfg(feature = "lang-fr")]
+    (Language::French, fr::VARIANTS, fr::ABBREVIATIONS),
+    #[cfg(feature = "lang-de")]
+    (Language::German, de::VARIANTS, de::ABBREVIATIONS),
+    #[cfg(feature = "lang-it")]
+    (Language::Italian, it::VARIANTS, it::ABBREVIATIONS),
+    #[cfg(feature = "lang-pl")]
+    (Language::Polish, pl::VARIANTS, pl::ABBREVIATIONS),
+    #[cfg(feature = "lang-ru")]
+    (Language::Russian, ru::VARIANTS, ru::ABBREVIATIONS),
+    #[cfg(feature = "lang-ar")]
+    (Language::Arabic, ar::VARIANTS, ar::ABBREVIATIONS),
+    #[cfg(feature = "lang-zh")]
+    (Language::Chinese, zh::VARIANTS, zh::ABBREVIATIONS),
+    #[cfg(feature = "lang-sv")]
+    (Language::Swedish, sv::VARIANTS, sv::ABBREVIATIONS),
+    #[cfg(feature = "lang-no")]
+    (Language::Norwegian, no::VARIANTS, no::ABBREVIATIONS),
+    #[cfg(feature = "lang-da")]
+    (Language::Danish, da::VARIANTS, da::ABBREVIATIONS),
+    #[cfg(feature = "lang-ja")]
+    (Language::Japanese, ja::VARIANTS, ja::ABBREVIATIONS),
+    #[cfg(feature = "lang-ko")]
+    (Language::Korean, ko::VARIANTS, ko::ABBREVIATIONS),
+    #[cfg(feature = "lang-tr")]
+    (Language::Turkish, tr::VARIANTS, tr::ABBREVIATIONS),
+    #[cfg(feature = "lang-hi")]
+    (Language::Hindi, hi::VARIANTS, hi::ABBREVIATIONS),
+    #[cfg(feature = "lang-id")]
+    (Language::Indonesian, id::VARIANTS, id::ABBREVIATIONS),
+    #[cfg(feature = "lang-vi")]
+    (Language::Vietnamese, vi::VARIANTS, vi::ABBREVIATIONS),
+    #[cfg(feature = "lang-fi")]
+    (Language::Finnish, fi::VARIANTS, fi::ABBREVIATIONS),
+    #[cfg(feature = "lang-cs")]
+    (Language::Czech, cs::VARIANTS, cs::ABBREVIATIONS),
+    #[cfg(feature = "lang-sk")]
+    (Language::Slovak, sk::VARIANTS, sk::ABBREVIATIONS),
+    #[cfg(feature = "lang-hu")]
+    (Language::Hungarian, hu::VARIANTS, hu::ABBREVIATIONS),
+    #[cfg(feature = "lang-uk")]
+    (Language::Ukrainian, uk::VARIANTS, uk::ABBREVIATIONS),
+    #[cfg(feature = "lang-he")]
+    (Language::Hebrew, he::VARIANTS, he::ABBREVIATIONS),
+];
+
+/// English followed by the languages of [`TABLES`]
+const LANGUAGES: [Language; TABLES.len() + 1] = {
+    let mut languages = [Language::English; TABLES.len() + 1];
+    let mut index = 0;
+    while index < TABLES.len() {
+        languages[index + 1] = TABLES[index].0;
+        index += 1;
+    }
+    languages
+};
+
+/// All compiled-in languages, English first
+pub const fn languages() -> &'static [Language] {
+    &LANGUAGES
+}
+
+/// All compiled-in month names, tagged with their language
+pub fn variants() -> impl Iterator<Item = (&'static str, Month, Language)> {
+    TABLES.iter().flat_map(|(language, variants, _)| {
+        variants
+            .iter()
+            .map(move |(variant, month)| (*variant, *month, *language))
+    })
+}
+
+/// All compiled-in abbreviations, tagged with their language
+pub fn abbreviations() -> impl Iterator<Item = (&'static str, Month, Language)> {
+    TABLES.iter().flat_map(|(language, _, abbreviations)| {
+        abbreviations
+            .iter()
+            .map(move |(abbreviation, month)| (*abbreviation, *month, *language))
+    })
+}
+
+/// Match month names that embed a number, such as the Vietnamese `"tháng 3"`
+pub fn match_numbered(input: &str) -> Option<Month> {
+    let matchers: &[fn(&str) -> Option<Month>] = &[
+        #[cfg(feature = "lang-vi")]
+        vi::match_numbered,
+    ];
+    matchers.iter().find_map(|matcher| matcher(input))
+}
@@ -0,0 +1,36 @@
+//! Norwegian month names.
+
+use crate::Month;
+
+/// Norwegian month names
+pub const VARIANTS: &[(&str, Month)] = &[
+    ("januar", Month::January),
+    ("februar", Month::February),
+    ("mars", Month::March),
+    ("april", Month::April),
+    ("mai", Month::May),
+    ("juni", Month::June),
+    ("juli", Month::July),
+    ("august", Month::August),
+    ("september", Month::September),
+    ("oktober", Month::October),
+    ("november", Month::November),
+    ("desember", Month::December),
+];
+
+/// Abbreviated Norwegian month names
+///
+/// `"des"` (December) is left out, as it is a common French word.
+pub const ABBREVIATIONS: &[(&str, Month)] = &[
+    ("jan", Month::January),
+    ("feb", Month::February),
+    ("mar", Month::March),
+    ("apr", Month::April),
+    ("mai", Month::May),
+    ("jun", Month::June),
+    ("jul", Month::July),
+    ("aug", Month::August),
+    ("sep", Month::September),
+    ("okt", Month::October),
+    ("nov", Month::November),
+];
@@ -0,0 +1,50 @@
+//! Polish month names.
+
+use crate::Month;
+
+/// Polish month names
+///
+/// Dates use the genitive case (`"15 stycznia"`), so both the nominative and
+/// the genitive forms are listed.
+pub const VARIANTS: &[(&str, Month)] = &[
+    ("styczeń", Month::January),
+    ("stycznia", Month::January), // genitive
+    ("luty", Month::February),
+    ("lutego", Month::February), // genitive
+    ("marzec", Month::March),
+    ("marca", Month::March), // genitive
+    ("kwiecień", Month::April),
+    ("kwietnia", Month::April), // genitive
+    ("maj", Month::May),
+    ("maja", Month::May), // genitive
+    ("czerwiec", Month::June),
+    ("czerwca", Month::June), // genitive
+    ("lipiec", Month::July),
+    ("lipca", Month::July), // genitive
+    ("sierpień", Month::August),
+    ("sierpnia", Month::August), // genitive
+    ("wrzesień", Month::September),
+    ("września", Month::September), // genitive
+    ("październik", Month::October),
+    ("października", Month::October), // genitive
+    ("listopad", Month::November),
+    ("listopada", Month::November), // genitive
+    ("grudzień", Month::December),
+    ("grudnia", Month::December), // genitive
+];
+
+/// Abbreviated Polish month names
+pub const ABBREVIATIONS: &[(&str, Month)] = &[
+    ("sty", Month::January),
+    ("lut", Month::February),
+    ("mar", Month::March),
+    ("kwi", Month::April),
+    ("maj", Month::May),
+    ("cze", Month::June),
+    ("lip", Month::July),
+    ("sie", Month::August),
+    ("wrz", Month::September),
+    ("paź", Month::October),
+    ("lis", Month::November),
+    ("gru", Month::December),
+];
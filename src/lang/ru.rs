@@ -0,0 +1,50 @@
+//! Russian month names.
+
+use crate::Month;
+
+/// Russian month names
+///
+/// Dates use the genitive case (`"15 января"`), so both the nominative and
+/// the genitive forms are listed.
+pub const VARIANTS: &[(&str, Month)] = &[
+    ("январь", Month::January),
+    ("января", Month::January), // genitive
+    ("февраль", Month::February),
+    ("февраля", Month::February), // genitive
+    ("март", Month::March),
+    ("марта", Month::March), // genitive
+    ("апрель", Month::April),
+    ("апреля", Month::April), // genitive
+    ("май", Month::May),
+    ("мая", Month::May), // genitive
+    ("июнь", Month::June),
+    ("июня", Month::June), // genitive
+    ("июль", Month::July),
+    ("июля", Month::July), // genitive
+    ("август", Month::August),
+    ("августа", Month::August), // genitive
+    ("сентябрь", Month::September),
+    ("сентября", Month::September), // genitive
+    ("октябрь", Month::October),
+    ("октября", Month::October), // genitive
+    ("ноябрь", Month::November),
+    ("ноября", Month::November), // genitive
+    ("декабрь", Month::December),
+    ("декабря", Month::December), // genitive
+];
+
+/// Abbreviated Russian month names
+pub const ABBREVIATIONS: &[(&str, Month)] = &[
+    ("янв", Month::January),
+    ("фев", Month::February),
+    ("мар", Month::March),
+    ("апр", Month::April),
+    ("май", Month::May),
+    ("июн", Month::June),
+    ("июл", Month::July),
+    ("авг", Month::August),
+    ("сен", Month::September),
+    ("окт", Month::October),
+    ("ноя", Month::November),
+    ("дек", Month::December),
+];
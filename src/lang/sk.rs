@@ -0,0 +1,36 @@
+//! Slovak month names.
+
+use crate::Month;
+
+/// Slovak month names
+///
+/// Slovak uses the Latin month names (`"január"`), unlike Czech (`"leden"`).
+/// Dates use the genitive (`"1. januára"`), which is listed as well.
+pub const VARIANTS: &[(&str, Month)] = &[
+    ("január", Month::January),
+    ("januára", Month::January), // genitive
+    ("február", Month::February),
+    ("februára", Month::February), // genitive
+    ("marec", Month::March),
+    ("marca", Month::March), // genitive
+    ("apríl", Month::April),
+    ("apríla", Month::April), // genitive
+    ("máj", Month::May),
+    ("mája", Month::May), // genitive
+    ("jún", Month::June),
+    ("júna", Month::June), // genitive
+    ("júl", Month::July),
+    ("júla", Month::July), // genitive
+    ("august", Month::August),
+    ("augusta", Month::August), // genitive
+    ("september", Month::September),
+    ("septembra", Month::September), // genitive
+    ("október", Month::October),
+    ("októbra", Month::October), // genitive
+    ("november", Month::November),
+    ("novembra", Month::November), // genitive
+    ("december", Month::December),
+    ("decembra", Month::December), // genitive
+];
+
+pub const ABBREVIATIONS: &[(&str, Month)] = &[];
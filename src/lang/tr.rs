@@ -0,0 +1,35 @@
+//! Turkish month names.
+
+use crate::Month;
+
+/// Turkish month names
+pub const VARIANTS: &[(&str, Month)] = &[
+    ("ocak", Month::January),
+    ("şubat", Month::February),
+    ("mart", Month::March),
+    ("nisan", Month::April),
+    ("mayıs", Month::May),
+    ("haziran", Month::June),
+    ("temmuz", Month::July),
+    ("ağustos", Month::August),
+    ("eylül", Month::September),
+    ("ekim", Month::October),
+    ("kasım", Month::November),
+    ("aralık", Month::December),
+];
+
+/// Abbreviated Turkish month names
+pub const ABBREVIATIONS: &[(&str, Month)] = &[
+    ("oca", Month::January),
+    ("şub", Month::February),
+    ("mar", Month::March),
+    ("nis", Month::April),
+    ("may", Month::May),
+    ("haz", Month::June),
+    ("tem", Month::July),
+    ("ağu", Month::August),
+    ("eyl", Month::September),
+    ("eki", Month::October),
+    ("kas", Month::November),
+    ("ara", Month::December),
+];
@@ -0,0 +1,52 @@
+//! Ukrainian month names.
+
+use crate::Month;
+
+/// Ukrainian month names
+///
+/// Unlike Russian, Ukrainian uses Slavic month names (`"січень"` is January).
+/// Dates use the genitive (`"1 січня"`), which is listed as well. The
+/// Ukrainian letters `і`, `ї` and `є` are matched as written, as case folding
+/// keeps them distinct from each other and from Russian letters.
+pub const VARIANTS: &[(&str, Month)] = &[
+    ("січень", Month::January),
+    ("січня", Month::January), // genitive
+    ("лютий", Month::February),
+    ("лютого", Month::February), // genitive
+    ("березень", Month::March),
+    ("березня", Month::March), // genitive
+    ("квітень", Month::April),
+    ("квітня", Month::April), // genitive
+    ("травень", Month::May),
+    ("травня", Month::May), // genitive
+    ("червень", Month::June),
+    ("червня", Month::June), // genitive
+    ("липень", Month::July),
+    ("липня", Month::July), // genitive
+    ("серпень", Month::August),
+    ("серпня", Month::August), // genitive
+    ("вересень", Month::September),
+    ("вересня", Month::September), // genitive
+    ("жовтень", Month::October),
+    ("жовтня", Month::October), // genitive
+    ("листопад", Month::November),
+    ("листопада", Month::November), // genitive
+    ("грудень", Month::December),
+    ("грудня", Month::December), // genitive
+];
+
+/// Abbreviated month names
+pub const ABBREVIATIONS: &[(&str, Month)] = &[
+    ("січ", Month::January),
+    ("лют", Month::February),
+    ("бер", Month::March),
+    ("квіт", Month::April),
+    ("трав", Month::May),
+    ("черв", Month::June),
+    ("лип", Month::July),
+    ("серп", Month::August),
+    ("вер", Month::September),
+    ("жовт", Month::October),
+    ("лист", Month::November),
+    ("груд", Month::December),
+];
@@ -0,0 +1,66 @@
+//! Vietnamese month names.
+
+use crate::{month_from_number, normalize, Month};
+
+/// Vietnamese month names
+///
+/// Months are called `"tháng"` ("month") followed by their number, either
+/// spelled out as listed here or written with digits (see
+/// [`match_numbered`]). January and December also have the traditional
+/// names `"tháng giêng"` and `"tháng chạp"`.
+pub const VARIANTS: &[(&str, Month)] = &[
+    ("tháng một", Month::January),
+    ("tháng giêng", Month::January),
+    ("tháng hai", Month::February),
+    ("tháng ba", Month::March),
+    ("tháng tư", Month::April),
+    ("tháng bốn", Month::April),
+    ("tháng năm", Month::May),
+    ("tháng sáu", Month::June),
+    ("tháng bảy", Month::July),
+    ("tháng tám", Month::August),
+    ("tháng chín", Month::September),
+    ("tháng mười", Month::October),
+    ("tháng mười một", Month::November),
+    ("tháng mười hai", Month::December),
+    ("tháng chạp", Month::December),
+];
+
+/// There are no abbreviations; `"tháng 3"` (see [`match_numbered`]) is
+/// already the short form.
+pub const ABBREVIATIONS: &[(&str, Month)] = &[];
+
+/// Match `"tháng"` followed by the month number in digits, e.g. `"tháng 3"`
+///
+/// Like all variants, the prefix is also accepted without diacritics
+/// (`"thang 3"`), and the number may have a leading zero.
+pub fn match_numbered(input: &str) -> Option<Month> {
+    let (prefix, number) = input.split_once(char::is_whitespace)?;
+    if !normalize::folded_eq(prefix, "tháng") {
+        return None;
+    }
+    let number = number.trim_start();
+    if number.is_empty() || number.len() > 2 || !number.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    number.parse().ok().and_then(month_from_number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("tháng 13")]
+    #[case("tháng 0")]
+    #[case("tháng 123")]
+    #[case("tháng ")]
+    #[case("tháng")]
+    #[case("tháng 3a")]
+    #[case("năm 3")]
+    fn test_match_numbered_invalid(#[case] input: &str) {
+        assert_eq!(match_numbered(input), None);
+    }
+}
@@ -0,0 +1,32 @@
+//! Chinese month names.
+
+use crate::Month;
+
+/// Chinese month names
+///
+/// Besides the numbered `N月` forms, the traditional names `"正月"` (first
+/// month), `"冬月"` (eleventh month) and `"腊月"` (twelfth month, also written
+/// `"臘月"`) are accepted. They are mapped to the month with the same number as
+/// a naming convenience; no lunar-calendar conversion takes place.
+pub const VARIANTS: &[(&str, Month)] = &[
+    ("一月", Month::January),
+    ("正月", Month::January), // traditional
+    ("二月", Month::February),
+    ("三月", Month::March),
+    ("四月", Month::April),
+    ("五月", Month::May),
+    ("六月", Month::June),
+    ("七月", Month::July),
+    ("八月", Month::August),
+    ("九月", Month::September),
+    ("十月", Month::October),
+    ("十一月", Month::November),
+    ("冬月", Month::November), // traditional
+    ("十二月", Month::December),
+    ("腊月", Month::December), // traditional
+    ("臘月", Month::December), // traditional
+];
+
+/// The `N月` forms are as short as month names get, so there are no
+/// abbreviations.
+pub const ABBREVIATIONS: &[(&str, Month)] = &[];
@@ -20,356 +20,3862 @@
 //! assert_eq!(parse_month("sebtembar").unwrap(), Month::September);
 //! ```
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![deny(clippy::all)]
 #![warn(clippy::pedantic)]
 #![warn(clippy::as_conversions)]
 #![warn(clippy::nursery)]
 #![warn(clippy::cargo)]
 
-#[cfg(test)]
+extern crate alloc;
+
+// The README examples assume the default features
+#[cfg(all(test, feature = "all-languages"))]
 #[macro_use]
 extern crate doc_comment;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "all-languages"))]
 doctest!("../README.md");
 
-#[cfg(doctest)]
-doc_comment::doctest!("../../README.md");
+#[cfg(all(doctest, feature = "all-languages"))]
+doc_comment::doctest!("../README.md");
+
+#[cfg(feature = "std")]
+mod cache;
+mod extract;
+mod lang;
+mod normalize;
+mod phonetic;
+mod range;
+mod set;
+mod similarity;
+#[cfg(feature = "wasm")]
+mod wasm;
+
+pub use extract::extract_months;
+pub use range::{MonthRange, MonthRangeIter};
+pub use set::{MonthSet, MonthSetIter};
+pub use similarity::{KeyboardDistance, KeyboardLayout, Levenshtein, SimilarityMetric};
+#[cfg(feature = "wasm")]
+pub use wasm::{parse_month_js, parse_month_with_score_js};
+
+use alloc::{format, string::String, vec::Vec};
+use core::fmt;
+
+use similarity::{levenshtein, normalized_levenshtein};
+
+/// Every month with its English name and number, in calendar order
+///
+/// This is the single source of the names; the month at index `i` is the
+/// variant with [`Month::index`] `i`, and the numbers are its discriminants.
+const MONTHS: [(Month, &str, u8); 12] = [
+    (Month::January, "January", 1),
+    (Month::February, "February", 2),
+    (Month::March, "March", 3),
+    (Month::April, "April", 4),
+    (Month::May, "May", 5),
+    (Month::June, "June", 6),
+    (Month::July, "July", 7),
+    (Month::August, "August", 8),
+    (Month::September, "September", 9),
+    (Month::October, "October", 10),
+    (Month::November, "November", 11),
+    (Month::December, "December", 12),
+];
+
+/// Month of the year
+///
+/// The [`Default`] is January, the first month of the calendar year, so that
+/// structs holding a `Month` can derive `Default`.
+///
+/// The discriminants are the calendar numbers and will stay that way, so
+/// `Month::March as u8` is 3, e.g. to pass months across an FFI boundary.
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+#[repr(u8)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug, PartialEq, Eq))
+)]
+pub enum Month {
+    #[default]
+    January = 1,
+    February = 2,
+    March = 3,
+    April = 4,
+    May = 5,
+    June = 6,
+    July = 7,
+    August = 8,
+    September = 9,
+    October = 10,
+    November = 11,
+    December = 12,
+}
+
+impl Month {
+    /// English name of the month
+    ///
+    /// ```
+    /// use fuzzymonth::Month;
+    ///
+    /// assert_eq!(Month::September.name(), "September");
+    /// ```
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        MONTHS[self.index()].1
+    }
+
+    /// Three-letter English abbreviation of the month
+    ///
+    /// ```
+    /// use fuzzymonth::Month;
+    ///
+    /// assert_eq!(Month::September.abbreviation(), "Sep");
+    /// ```
+    #[must_use]
+    pub const fn abbreviation(self) -> &'static str {
+        ascii_prefix(self.name().as_bytes(), 3)
+    }
+
+    /// Number of the month in the calendar (1 to 12)
+    ///
+    /// ```
+    /// use fuzzymonth::Month;
+    ///
+    /// assert_eq!(Month::September.number(), 9);
+    /// ```
+    #[must_use]
+    pub const fn number(self) -> u8 {
+        // The discriminants are the calendar numbers
+        #[allow(clippy::as_conversions)]
+        let number = self as u8;
+        number
+    }
+
+    /// Zero-based index of the month (0 for January to 11 for December)
+    ///
+    /// Useful for indexing arrays with one entry per month, where
+    /// [`Month::number`] would be off by one.
+    ///
+    /// ```
+    /// use fuzzymonth::Month;
+    ///
+    /// let days = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    /// assert_eq!(days[Month::February.index()], 28);
+    /// ```
+    #[must_use]
+    pub const fn index(self) -> usize {
+        // A u8 always fits into a usize
+        #[allow(clippy::as_conversions)]
+        let index = (self.number() - 1) as usize;
+        index
+    }
+
+    /// The month at a zero-based index, the inverse of [`Month::index`]
+    ///
+    /// ```
+    /// use fuzzymonth::Month;
+    ///
+    /// assert_eq!(Month::from_index(11), Ok(Month::December));
+    /// assert!(Month::from_index(12).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationError::OutOfRange`] if the index is not between 0
+    /// and 11.
+    pub fn from_index(index: usize) -> Result<Self, ValidationError> {
+        u32::try_from(index)
+            .ok()
+            .and_then(|index| index.checked_add(1))
+            .and_then(month_from_number)
+            .ok_or_else(|| {
+                ValidationError::OutOfRange(format!(
+                    "Month index out of range: {index}. Enter an index from 0 to 11"
+                ))
+            })
+    }
+
+    /// All twelve months in calendar order
+    ///
+    /// ```
+    /// use fuzzymonth::Month;
+    ///
+    /// assert_eq!(Month::all()[0], Month::January);
+    /// assert_eq!(Month::all().len(), 12);
+    /// ```
+    #[must_use]
+    pub const fn all() -> [Self; 12] {
+        let mut all = [Self::January; 12];
+        let mut index = 0;
+        while index < MONTHS.len() {
+            all[index] = MONTHS[index].0;
+            index += 1;
+        }
+        all
+    }
+
+    /// The month after this one, wrapping from December to January
+    ///
+    /// ```
+    /// use fuzzymonth::Month;
+    ///
+    /// assert_eq!(Month::December.succ(), Month::January);
+    /// ```
+    #[must_use]
+    pub const fn succ(self) -> Self {
+        match self {
+            Self::January => Self::February,
+            Self::February => Self::March,
+            Self::March => Self::April,
+            Self::April => Self::May,
+            Self::May => Self::June,
+            Self::June => Self::July,
+            Self::July => Self::August,
+            Self::August => Self::September,
+            Self::September => Self::October,
+            Self::October => Self::November,
+            Self::November => Self::December,
+            Self::December => Self::January,
+        }
+    }
+
+    /// The month before this one, wrapping from January to December
+    ///
+    /// ```
+    /// use fuzzymonth::Month;
+    ///
+    /// assert_eq!(Month::January.pred(), Month::December);
+    /// ```
+    #[must_use]
+    pub const fn pred(self) -> Self {
+        match self {
+            Self::January => Self::December,
+            Self::February => Self::January,
+            Self::March => Self::February,
+            Self::April => Self::March,
+            Self::May => Self::April,
+            Self::June => Self::May,
+            Self::July => Self::June,
+            Self::August => Self::July,
+            Self::September => Self::August,
+            Self::October => Self::September,
+            Self::November => Self::October,
+            Self::December => Self::November,
+        }
+    }
+
+    /// The month `months` months after this one (or before, if negative),
+    /// wrapping around the end of the year
+    ///
+    /// ```
+    /// use fuzzymonth::Month;
+    ///
+    /// assert_eq!(Month::November.add_months(3), Month::February);
+    /// assert_eq!(Month::January.add_months(-1), Month::December);
+    /// assert_eq!(Month::March.add_months(24), Month::March);
+    /// ```
+    #[must_use]
+    pub const fn add_months(self, months: i32) -> Self {
+        // The sum can't overflow an i64, and its remainder is between 0 and 11
+        #[allow(
+            clippy::as_conversions,
+            clippy::cast_possible_truncation,
+            clippy::cast_possible_wrap,
+            clippy::cast_sign_loss
+        )]
+        let index = (self.index() as i64 + months as i64).rem_euclid(12) as usize;
+        Self::all()[index]
+    }
+
+    /// The month `months` months after this one (or before, if negative),
+    /// staying within the same year
+    ///
+    /// Unlike [`Month::add_months`], which wraps around, this stops at
+    /// December when going forward and at January when going back.
+    ///
+    /// ```
+    /// use fuzzymonth::Month;
+    ///
+    /// assert_eq!(Month::November.saturating_add_months(5), Month::December);
+    /// assert_eq!(Month::February.saturating_add_months(-5), Month::January);
+    /// assert_eq!(Month::March.saturating_add_months(2), Month::May);
+    /// ```
+    #[must_use]
+    pub const fn saturating_add_months(self, months: i32) -> Self {
+        // The sum can't overflow an i64
+        #[allow(clippy::as_conversions, clippy::cast_possible_wrap)]
+        let index = self.index() as i64 + months as i64;
+        if index < 0 {
+            Self::January
+        } else if index > 11 {
+            Self::December
+        } else {
+            // Between 0 and 11 after the checks above
+            #[allow(
+                clippy::as_conversions,
+                clippy::cast_possible_truncation,
+                clippy::cast_sign_loss
+            )]
+            let index = index as usize;
+            Self::all()[index]
+        }
+    }
+
+    /// All twelve months, starting at `start` and wrapping around the end of
+    /// the year
+    ///
+    /// Useful for fiscal years that don't start in January.
+    ///
+    /// ```
+    /// use fuzzymonth::Month;
+    ///
+    /// let months: Vec<Month> = Month::iter_from(Month::April).collect();
+    /// assert_eq!(months.len(), 12);
+    /// assert_eq!(months[0], Month::April);
+    /// assert_eq!(months[11], Month::March);
+    /// ```
+    #[must_use]
+    pub fn iter_from(start: Self) -> MonthRangeIter {
+        MonthRange::new(start, start.pred()).into_iter()
+    }
+
+    /// Calendar quarter of the month (1 to 4)
+    ///
+    /// ```
+    /// use fuzzymonth::Month;
+    ///
+    /// assert_eq!(Month::March.quarter(), 1);
+    /// assert_eq!(Month::October.quarter(), 4);
+    /// ```
+    #[must_use]
+    pub const fn quarter(self) -> u8 {
+        match self {
+            Self::January | Self::February | Self::March => 1,
+            Self::April | Self::May | Self::June => 2,
+            Self::July | Self::August | Self::September => 3,
+            Self::October | Self::November | Self::December => 4,
+        }
+    }
+
+    /// Position of the month within a fiscal year starting at `fiscal_start` (1 to 12)
+    ///
+    /// ```
+    /// use fuzzymonth::Month;
+    ///
+    /// assert_eq!(Month::April.fiscal_month(Month::April), 1);
+    /// assert_eq!(Month::March.fiscal_month(Month::April), 12);
+    /// ```
+    #[must_use]
+    pub const fn fiscal_month(self, fiscal_start: Self) -> u8 {
+        (self.number() + 12 - fiscal_start.number()) % 12 + 1
+    }
+
+    /// Quarter of the month within a fiscal year starting at `fiscal_start` (1 to 4)
+    ///
+    /// With a fiscal start of [`Month::January`] this is the same as [`Month::quarter`].
+    ///
+    /// ```
+    /// use fuzzymonth::Month;
+    ///
+    /// assert_eq!(Month::April.fiscal_quarter(Month::April), 1);
+    /// assert_eq!(Month::March.fiscal_quarter(Month::April), 4);
+    /// ```
+    #[must_use]
+    pub const fn fiscal_quarter(self, fiscal_start: Self) -> u8 {
+        (self.fiscal_month(fiscal_start) - 1) / 3 + 1
+    }
+
+    /// Number of days in the month in a common (non-leap) year
+    ///
+    /// ```
+    /// use fuzzymonth::Month;
+    ///
+    /// assert_eq!(Month::February.days(), 28);
+    /// assert_eq!(Month::April.days(), 30);
+    /// ```
+    #[must_use]
+    pub const fn days(self) -> u8 {
+        match self {
+            Self::February => 28,
+            Self::April | Self::June | Self::September | Self::November => 30,
+            _ => 31,
+        }
+    }
+
+    /// Number of days in the month in the given year
+    ///
+    /// Uses the leap year rule of the proleptic Gregorian calendar, so
+    /// February has 29 days in 2000 but only 28 in 1900.
+    ///
+    /// ```
+    /// use fuzzymonth::Month;
+    ///
+    /// assert_eq!(Month::February.days_in_month(2024), 29);
+    /// assert_eq!(Month::February.days_in_month(2023), 28);
+    /// ```
+    #[must_use]
+    pub const fn days_in_month(self, year: i32) -> u8 {
+        match self {
+            Self::February if is_leap_year(year) => 29,
+            _ => self.days(),
+        }
+    }
+
+    /// The month together with its last day in the given year
+    ///
+    /// Handy for building end-of-month dates; the day accounts for leap years
+    /// like [`Month::days_in_month`].
+    ///
+    /// ```
+    /// use fuzzymonth::Month;
+    ///
+    /// assert_eq!(Month::February.last_day(2024), (Month::February, 29));
+    /// assert_eq!(Month::November.last_day(2023), (Month::November, 30));
+    /// ```
+    #[must_use]
+    pub const fn last_day(self, year: i32) -> (Self, u8) {
+        (self, self.days_in_month(year))
+    }
+
+    /// Meteorological season of the month in the given hemisphere
+    ///
+    /// Meteorological seasons span whole months: in the northern hemisphere
+    /// winter is December to February, spring March to May, summer June to
+    /// August and autumn September to November. The southern hemisphere has
+    /// the opposite season.
+    ///
+    /// ```
+    /// use fuzzymonth::{Hemisphere, Month, Season};
+    ///
+    /// assert_eq!(Month::July.season(Hemisphere::Northern), Season::Summer);
+    /// assert_eq!(Month::July.season(Hemisphere::Southern), Season::Winter);
+    /// ```
+    #[must_use]
+    pub const fn season(self, hemisphere: Hemisphere) -> Season {
+        let northern = match self {
+            Self::December | Self::January | Self::February => Season::Winter,
+            Self::March | Self::April | Self::May => Season::Spring,
+            Self::June | Self::July | Self::August => Season::Summer,
+            Self::September | Self::October | Self::November => Season::Autumn,
+        };
+        match hemisphere {
+            Hemisphere::Northern => northern,
+            Hemisphere::Southern => northern.opposite(),
+        }
+    }
+
+    /// An emoji for the (meteorological) season of the month
+    ///
+    /// ❄ for winter, 🌸 for spring, 🌞 for summer and 🍂 for autumn, so the
+    /// hemisphere decides which months get snow. A `char` can't hold the
+    /// variation selector of `"❄️"`, so the snowflake may be rendered as text
+    /// rather than as a colored emoji; append `'\u{fe0f}'` if that matters.
+    ///
+    /// ```
+    /// use fuzzymonth::{Hemisphere, Month};
+    ///
+    /// assert_eq!(Month::January.to_emoji(Hemisphere::Northern), '❄');
+    /// assert_eq!(Month::January.to_emoji(Hemisphere::Southern), '🌞');
+    /// ```
+    #[must_use]
+    pub const fn to_emoji(self, hemisphere: Hemisphere) -> char {
+        match self.season(hemisphere) {
+            Season::Winter => '❄',
+            Season::Spring => '🌸',
+            Season::Summer => '🌞',
+            Season::Autumn => '🍂',
+        }
+    }
+
+    /// Astronomical season of the month in the given hemisphere
+    ///
+    /// Astronomical seasons start at the equinoxes and solstices, around the
+    /// 20th to 22nd of March, June, September and December. At month
+    /// resolution, each month is assigned the season it spends most of its
+    /// days in, so the season changes one month later than with
+    /// [`Month::season`]: in the northern hemisphere March is still winter,
+    /// and spring is April to June. The southern hemisphere has the opposite
+    /// season.
+    ///
+    /// ```
+    /// use fuzzymonth::{Hemisphere, Month, Season};
+    ///
+    /// assert_eq!(Month::March.season(Hemisphere::Northern), Season::Spring);
+    /// assert_eq!(
+    ///     Month::March.astronomical_season(Hemisphere::Northern),
+    ///     Season::Winter
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn astronomical_season(self, hemisphere: Hemisphere) -> Season {
+        self.pred().season(hemisphere)
+    }
+
+    /// The zodiac sign covering most of the month
+    ///
+    /// Signs change around the 20th, so this is the sign that began in the
+    /// previous month: March is mostly Pisces (until March 20), not Aries.
+    /// This is only an approximation at month granularity, using the usual
+    /// tropical dates.
+    ///
+    /// ```
+    /// use fuzzymonth::Month;
+    ///
+    /// assert_eq!(Month::March.zodiac_sign(), "Pisces");
+    /// assert_eq!(Month::January.zodiac_sign(), "Capricorn");
+    /// ```
+    #[must_use]
+    pub const fn zodiac_sign(self) -> &'static str {
+        match self {
+            Self::January => "Capricorn",
+            Self::February => "Aquarius",
+            Self::March => "Pisces",
+            Self::April => "Aries",
+            Self::May => "Taurus",
+            Self::June => "Gemini",
+            Self::July => "Cancer",
+            Self::August => "Leo",
+            Self::September => "Virgo",
+            Self::October => "Libra",
+            Self::November => "Scorpio",
+            Self::December => "Sagittarius",
+        }
+    }
+
+    /// Name of the month in the given language
+    ///
+    /// Returns the canonical lowercase spelling, e.g. `"mars"` for
+    /// [`Language::French`]. Where a language has several forms (such as the
+    /// Russian genitive), the primary nominative form is returned. Languages
+    /// whose `lang-xx` feature is disabled fall back to the English name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fuzzymonth::{Language, Month};
+    ///
+    /// # #[cfg(feature = "lang-fr")]
+    /// assert_eq!(Month::March.localized_name(Language::French), "mars");
+    /// # #[cfg(feature = "lang-de")]
+    /// assert_eq!(Month::December.localized_name(Language::German), "dezember");
+    /// ```
+    #[must_use]
+    pub fn localized_name(self, lang: Language) -> &'static str {
+        let english = MONTH_NAMES
+            .iter()
+            .find(|(_, month)| *month == self)
+            .map_or("", |(name, _)| name);
+        if lang == Language::English {
+            return english;
+        }
+
+        lang::variants()
+            .find(|(_, month, language)| *month == self && *language == lang)
+            .map_or(english, |(name, _, _)| name)
+    }
+
+    /// Name of the month in the given language, with the first letter
+    /// capitalized for display
+    ///
+    /// This is [`Month::localized_name`] in title case, e.g. `"Enero"` for
+    /// [`Language::Spanish`]. Scripts without letter case, such as Chinese,
+    /// are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fuzzymonth::{Language, Month};
+    ///
+    /// assert_eq!(Month::March.localized_title(Language::English), "March");
+    /// # #[cfg(feature = "lang-es")]
+    /// assert_eq!(Month::January.localized_title(Language::Spanish), "Enero");
+    /// ```
+    #[must_use]
+    pub fn localized_title(self, lang: Language) -> String {
+        let name = self.localized_name(lang);
+        let mut chars = name.chars();
+        chars.next().map_or_else(String::new, |first| {
+            first.to_uppercase().chain(chars).collect()
+        })
+    }
+}
+
+/// The languages whose month names are recognized, English first
+///
+/// English is always supported; the others depend on the enabled `lang-xx`
+/// features, e.g. for a help screen listing the supported languages.
+///
+/// ```
+/// use fuzzymonth::{supported_languages, Language};
+///
+/// assert_eq!(supported_languages()[0], Language::English);
+/// # #[cfg(feature = "lang-fr")]
+/// assert!(supported_languages().contains(&Language::French));
+/// ```
+#[must_use]
+pub const fn supported_languages() -> &'static [Language] {
+    lang::languages()
+}
+
+/// Number of months from `from` to `to`, each a month in a year
+///
+/// The result is negative if `to` comes before `from`, and saturates at the
+/// bounds of an `i32` for extreme years.
+///
+/// ```
+/// use fuzzymonth::{months_between, Month};
+///
+/// assert_eq!(months_between((Month::December, 2023), (Month::February, 2024)), 2);
+/// assert_eq!(months_between((Month::March, 2024), (Month::January, 2024)), -2);
+/// ```
+#[must_use]
+pub fn months_between(from: (Month, i32), to: (Month, i32)) -> i32 {
+    let months = i32::from(to.0.number()) - i32::from(from.0.number());
+    to.1.saturating_sub(from.1)
+        .saturating_mul(12)
+        .saturating_add(months)
+}
+
+/// Language of a month name
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum Language {
+    English,
+    Spanish,
+    French,
+    German,
+    Italian,
+    Polish,
+    Russian,
+    Arabic,
+    Chinese,
+    Swedish,
+    Norwegian,
+    Danish,
+    Japanese,
+    Korean,
+    Turkish,
+    Hindi,
+    Indonesian,
+    Vietnamese,
+    Finnish,
+    Czech,
+    Slovak,
+    Hungarian,
+    Ukrainian,
+    Hebrew,
+}
+
+/// The lowercase English name of the month, the spelling that inputs are
+/// matched against (see [`Month::name`] for the capitalized name)
+///
+/// ```
+/// use fuzzymonth::Month;
+///
+/// let months = [Month::March, Month::May];
+/// let names: Vec<&str> = months.iter().map(AsRef::as_ref).collect();
+/// assert_eq!(names.join(", "), "march, may");
+/// ```
+impl AsRef<str> for Month {
+    fn as_ref(&self) -> &str {
+        MONTH_NAMES[self.index()].0
+    }
+}
+
+/// Compare with the English name of the month, ignoring ASCII case
+///
+/// Only the canonical spelling returned by [`Month::name`] is equal; this is
+/// not a parser, so abbreviations, typos and other languages are not.
+///
+/// ```
+/// use fuzzymonth::Month;
+///
+/// assert!(Month::May == "May");
+/// assert!(Month::May == "may");
+/// assert!(Month::May != "mai");
+/// ```
+impl PartialEq<str> for Month {
+    fn eq(&self, other: &str) -> bool {
+        self.name().eq_ignore_ascii_case(other)
+    }
+}
+
+/// Same as `PartialEq<str>`, for comparing with string literals
+impl PartialEq<&str> for Month {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+/// Parse a month with [`parse_month`], e.g. `"sept".parse::<Month>()`
+impl core::str::FromStr for Month {
+    type Err = ValidationError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        parse_month(value)
+    }
+}
+
+/// Parse a month with [`parse_month`], e.g. `Month::try_from("sept")`
+///
+/// ```
+/// use fuzzymonth::Month;
+///
+/// assert_eq!(Month::try_from("sept").unwrap(), Month::September);
+/// assert_eq!("sept".parse::<Month>().unwrap(), Month::September);
+/// assert!(Month::try_from("xyz").is_err());
+/// ```
+impl TryFrom<&str> for Month {
+    type Error = ValidationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        parse_month(value)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<Month> for chrono::Month {
+    fn from(month: Month) -> Self {
+        match month {
+            Month::January => Self::January,
+            Month::February => Self::February,
+            Month::March => Self::March,
+            Month::April => Self::April,
+            Month::May => Self::May,
+            Month::June => Self::June,
+            Month::July => Self::July,
+            Month::August => Self::August,
+            Month::September => Self::September,
+            Month::October => Self::October,
+            Month::November => Self::November,
+            Month::December => Self::December,
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::Month> for Month {
+    fn from(month: chrono::Month) -> Self {
+        match month {
+            chrono::Month::January => Self::January,
+            chrono::Month::February => Self::February,
+            chrono::Month::March => Self::March,
+            chrono::Month::April => Self::April,
+            chrono::Month::May => Self::May,
+            chrono::Month::June => Self::June,
+            chrono::Month::July => Self::July,
+            chrono::Month::August => Self::August,
+            chrono::Month::September => Self::September,
+            chrono::Month::October => Self::October,
+            chrono::Month::November => Self::November,
+            chrono::Month::December => Self::December,
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<Month> for time::Month {
+    fn from(month: Month) -> Self {
+        match month {
+            Month::January => Self::January,
+            Month::February => Self::February,
+            Month::March => Self::March,
+            Month::April => Self::April,
+            Month::May => Self::May,
+            Month::June => Self::June,
+            Month::July => Self::July,
+            Month::August => Self::August,
+            Month::September => Self::September,
+            Month::October => Self::October,
+            Month::November => Self::November,
+            Month::December => Self::December,
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Month> for Month {
+    fn from(month: time::Month) -> Self {
+        match month {
+            time::Month::January => Self::January,
+            time::Month::February => Self::February,
+            time::Month::March => Self::March,
+            time::Month::April => Self::April,
+            time::Month::May => Self::May,
+            time::Month::June => Self::June,
+            time::Month::July => Self::July,
+            time::Month::August => Self::August,
+            time::Month::September => Self::September,
+            time::Month::October => Self::October,
+            time::Month::November => Self::November,
+            time::Month::December => Self::December,
+        }
+    }
+}
+
+/// Whether `year` is a leap year in the proleptic Gregorian calendar
+const fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Hemisphere of the earth, which determines the season of a month
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum Hemisphere {
+    Northern,
+    Southern,
+}
+
+/// Season of the year
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    /// Season at the same time of the year in the other hemisphere
+    const fn opposite(self) -> Self {
+        match self {
+            Self::Spring => Self::Autumn,
+            Self::Summer => Self::Winter,
+            Self::Autumn => Self::Spring,
+            Self::Winter => Self::Summer,
+        }
+    }
+}
+
+/// Required similarity threshold for fuzzy matching to accept a date input
+///
+/// This is a lower threshold for more lenient matching
+///
+/// This is set on a best-effort basis based on testing
+const SIMILARITY_THRESHOLD: f64 = 0.75;
+
+/// Default maximum length of an input in characters, see
+/// [`MonthParser::max_input_len`]
+const MAX_INPUT_LEN: usize = 64;
+
+/// Inputs that are close to a month name, but more likely to mean something
+/// else, so they are rejected unless [`MonthParser::allow_ambiguous`] is set
+const AMBIGUOUS_INPUTS: &[&str] = &["marsh", "julie", "januori"];
+
+/// Which month wins when several are equally similar to the input
+///
+/// See [`MonthParser::tie_break`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Default)]
+pub enum TieBreak {
+    /// The month with the lowest number
+    Earliest,
+    /// The month with the highest number
+    #[default]
+    Latest,
+    /// The month whose name comes first in the table of names
+    First,
+}
+
+impl TieBreak {
+    /// Whether `candidate` wins a tie against the current `best` month
+    const fn prefers(self, candidate: Month, best: Month) -> bool {
+        match self {
+            Self::Earliest => candidate.number() < best.number(),
+            Self::Latest => candidate.number() > best.number(),
+            Self::First => false,
+        }
+    }
+}
+
+/// Error type for validation errors
+/// (e.g. invalid enum value)
+#[derive(Debug, PartialEq, Eq)]
+pub enum ValidationError {
+    InvalidEnumValue(String),
+    /// A month number outside of 1 to 12
+    OutOfRange(String),
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidEnumValue(message) | Self::OutOfRange(message) => f.write_str(message),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationError {}
+
+/// The length of the longest month name, "September"
+const MAX_NAME_LEN: usize = 9;
+
+/// The bytes of the lowercase names of [`MONTHS`], padded to the same length
+const LOWERCASE_NAMES: [[u8; MAX_NAME_LEN]; 12] = {
+    let mut names = [[0; MAX_NAME_LEN]; 12];
+    let mut index = 0;
+    while index < MONTHS.len() {
+        let name = MONTHS[index].1.as_bytes();
+        let mut byte = 0;
+        while byte < name.len() {
+            names[index][byte] = name[byte].to_ascii_lowercase();
+            byte += 1;
+        }
+        index += 1;
+    }
+    names
+};
+
+/// Map from month name to Month enum variant
+///
+/// The lowercase names of [`MONTHS`], in calendar order.
+const MONTH_NAMES: &[(&str, Month)] = &{
+    let mut names = [("", Month::January); 12];
+    let mut index = 0;
+    while index < MONTHS.len() {
+        let (month, name, _) = MONTHS[index];
+        names[index] = (ascii_prefix(&LOWERCASE_NAMES[index], name.len()), month);
+        index += 1;
+    }
+    names
+};
+
+/// Common English abbreviations of month names
+///
+/// The first three letters of the names in [`MONTH_NAMES`] (except May,
+/// which is short enough to be a name), followed by a few other common forms.
+const ABBREVIATIONS: &[(&str, Month)] = &{
+    const OTHERS: [(&str, Month); 2] = [("ja", Month::January), ("sept", Month::September)];
+    let mut abbreviations = [("", Month::January); 13];
+    let mut count = 0;
+    let mut index = 0;
+    while index < MONTH_NAMES.len() {
+        let (name, month) = MONTH_NAMES[index];
+        if name.len() > 3 {
+            abbreviations[count] = (ascii_prefix(name.as_bytes(), 3), month);
+            count += 1;
+        }
+        index += 1;
+    }
+    let mut other = 0;
+    while other < OTHERS.len() {
+        abbreviations[count] = OTHERS[other];
+        count += 1;
+        other += 1;
+    }
+    assert!(count == abbreviations.len());
+    abbreviations
+};
+
+/// The first `len` bytes of an ASCII string, as a string
+const fn ascii_prefix(bytes: &[u8], len: usize) -> &str {
+    match core::str::from_utf8(bytes.split_at(len).0) {
+        Ok(prefix) => prefix,
+        Err(_) => panic!("month names are ASCII"),
+    }
+}
+
+/// Spelled-out English cardinal and ordinal numbers ("one", "first", ...)
+///
+/// Number words of other languages clash with abbreviations, like the French
+/// `"sept"` (seven, and September), so they are not included.
+const NUMBER_WORDS: &[(&str, Month)] = &[
+    ("one", Month::January),
+    ("first", Month::January),
+    ("two", Month::February),
+    ("second", Month::February),
+    ("three", Month::March),
+    ("third", Month::March),
+    ("four", Month::April),
+    ("fourth", Month::April),
+    ("five", Month::May),
+    ("fifth", Month::May),
+    ("six", Month::June),
+    ("sixth", Month::June),
+    ("seven", Month::July),
+    ("seventh", Month::July),
+    ("eight", Month::August),
+    ("eighth", Month::August),
+    ("nine", Month::September),
+    ("ninth", Month::September),
+    ("ten", Month::October),
+    ("tenth", Month::October),
+    ("eleven", Month::November),
+    ("eleventh", Month::November),
+    ("twelve", Month::December),
+    ("twelfth", Month::December),
+];
+
+/// Ordinal indicators after the day of a date, as in the French "1er janvier"
+/// or the Spanish "1º de enero"
+const DAY_SUFFIXES: &[&str] = &["er", "re", "º", "ª", "°", ".º", ".ª"];
+
+/// Prepositions between the day and the month, as in the Spanish "3 de marzo"
+const DAY_PREPOSITIONS: &[&str] = &["de", "del"];
+
+/// Filler words that may surround a number word, as in "the first month"
+const NUMBER_FILLERS: &[&str] = &["the", "month", "of"];
+
+/// How the month of an input was recognized
+///
+/// See [`MonthMatch::kind`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum MatchKind {
+    /// A number, such as `"03"`, `"3rd"` or `"third"`
+    Numeric,
+    /// An English month name, such as `"January"`
+    Name,
+    /// An English abbreviation, such as `"Jan"` or `"Sept."`
+    Abbreviation,
+    /// A name or abbreviation in another language, such as `"enero"`
+    International,
+    /// A spelling added with [`MonthParser::with_extra_variants`]
+    Custom,
+    /// A spelling corrected by fuzzy (or phonetic) matching, such as `"janurary"`
+    Fuzzy,
+}
+
+/// The result of parsing a month, with details about how it was matched
+///
+/// See [`parse_month_detailed`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct MonthMatch {
+    pub month: Month,
+    /// Whether the input was an exact spelling (name, abbreviation, number or
+    /// international variant) rather than corrected by fuzzy matching
+    pub exact: bool,
+    /// Similarity to the English month name, 1.0 for exact matches
+    pub score: f64,
+    /// How the month was recognized, a finer distinction than `exact`
+    pub kind: MatchKind,
+    /// The input as it was matched: trimmed, case-folded and without
+    /// diacritics, and for fuzzy matches with leetspeak decoded
+    pub normalized_input: String,
+}
+
+impl MonthMatch {
+    /// An exact match of a month
+    const fn exact(month: Month, kind: MatchKind) -> Self {
+        Self {
+            month,
+            exact: true,
+            score: 1.0,
+            kind,
+            normalized_input: String::new(),
+        }
+    }
+
+    /// A fuzzy match of a month with its similarity
+    const fn fuzzy(month: Month, score: f64) -> Self {
+        Self {
+            month,
+            exact: false,
+            score,
+            kind: MatchKind::Fuzzy,
+            normalized_input: String::new(),
+        }
+    }
+}
+
+/// A month parser with configurable matching behavior
+///
+/// [`parse_month`] and friends use the default configuration; create a
+/// `MonthParser` to change it.
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::{parse_month, Month, MonthParser};
+///
+/// assert!(parse_month("marsh").is_err());
+///
+/// let parser = MonthParser::new().allow_ambiguous(true);
+/// assert_eq!(parser.parse("marsh").unwrap(), Month::March);
+/// ```
+///
+/// The similarity metric used for fuzzy matching is a type parameter, so it
+/// can be replaced with [`MonthParser::metric`].
+#[derive(Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)] // independent builder options
+pub struct MonthParser<M = Levenshtein> {
+    allow_ambiguous: bool,
+    phonetic: bool,
+    threshold: f64,
+    min_abbrev_len: usize,
+    tie_break: TieBreak,
+    max_input_len: usize,
+    extra_variants: Vec<(String, Month)>,
+    numeric: bool,
+    homoglyphs: bool,
+    #[cfg(feature = "std")]
+    cache: Option<cache::FuzzyCache>,
+    metric: M,
+}
+
+impl MonthParser {
+    /// Create a parser with the default configuration
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            allow_ambiguous: false,
+            phonetic: false,
+            threshold: SIMILARITY_THRESHOLD,
+            min_abbrev_len: 0,
+            tie_break: TieBreak::Latest,
+            max_input_len: MAX_INPUT_LEN,
+            extra_variants: Vec::new(),
+            numeric: true,
+            homoglyphs: false,
+            #[cfg(feature = "std")]
+            cache: None,
+            metric: Levenshtein,
+        }
+    }
+}
+
+impl Default for MonthParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: SimilarityMetric> MonthParser<M> {
+    /// Use a different similarity metric for fuzzy matching
+    ///
+    /// See [`KeyboardDistance`] for a metric that is more forgiving of typos
+    /// hitting a neighboring key.
+    #[must_use]
+    pub fn metric<N: SimilarityMetric>(self, metric: N) -> MonthParser<N> {
+        MonthParser {
+            allow_ambiguous: self.allow_ambiguous,
+            phonetic: self.phonetic,
+            threshold: self.threshold,
+            min_abbrev_len: self.min_abbrev_len,
+            tie_break: self.tie_break,
+            max_input_len: self.max_input_len,
+            extra_variants: self.extra_variants,
+            numeric: self.numeric,
+            homoglyphs: self.homoglyphs,
+            // Cached results of the old metric would be wrong for the new one
+            #[cfg(feature = "std")]
+            cache: self
+                .cache
+                .map(|cache| cache::FuzzyCache::new(cache.capacity())),
+            metric,
+        }
+    }
+
+    /// Fuzzy match inputs that are rejected by default as too ambiguous
+    ///
+    /// By default, inputs like `"marsh"` are rejected even though they are
+    /// close to a month name, because they are more likely to be a different
+    /// word than a typo. When enabled, these inputs go through regular fuzzy
+    /// matching, so `"marsh"` becomes March. Inputs that are not similar
+    /// enough to any month (like `"julie"`) are still rejected.
+    #[must_use]
+    pub const fn allow_ambiguous(mut self, allow: bool) -> Self {
+        self.allow_ambiguous = allow;
+        self
+    }
+
+    /// Fall back to phonetic matching when fuzzy matching fails
+    ///
+    /// Some misspellings sound right but are too far from the month name to
+    /// pass the similarity threshold, like `"jenyooary"` for January. When
+    /// enabled, inputs that fail fuzzy matching are compared to the English
+    /// month names with a simplified Metaphone encoding, and accepted if they
+    /// sound like exactly one month. The returned score is still the (below
+    /// threshold) similarity to that month.
+    ///
+    /// Only ASCII input is considered, and May is never matched phonetically,
+    /// as its encoding is too short to be distinctive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fuzzymonth::{parse_month, Month, MonthParser};
+    ///
+    /// assert!(parse_month("jenyooary").is_err());
+    ///
+    /// let parser = MonthParser::new().phonetic(true);
+    /// assert_eq!(parser.parse("jenyooary").unwrap(), Month::January);
+    /// ```
+    #[must_use]
+    pub const fn phonetic(mut self, phonetic: bool) -> Self {
+        self.phonetic = phonetic;
+        self
+    }
+
+    /// Set the similarity a fuzzy match needs to be accepted (default: 0.75)
+    ///
+    /// Lower values accept more distant typos, at the risk of turning
+    /// unrelated words into months; higher values are stricter. The threshold
+    /// should be between 0.0 and 1.0, where 1.0 disables fuzzy matching.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fuzzymonth::{parse_month, Month, MonthParser};
+    ///
+    /// assert!(parse_month("janry").is_err());
+    ///
+    /// let parser = MonthParser::new().threshold(0.6);
+    /// assert_eq!(parser.parse("janry").unwrap(), Month::January);
+    /// ```
+    #[must_use]
+    pub const fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Reject abbreviations shorter than `len` characters (default: 0)
+    ///
+    /// The abbreviation tables include very short forms like `"ja"` for
+    /// January, which can be too eager for some inputs. Abbreviations shorter
+    /// than `len` are rejected outright instead of being fuzzy matched. Full
+    /// month names such as `"may"` and numbers are not affected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fuzzymonth::{Month, MonthParser};
+    ///
+    /// assert_eq!(MonthParser::new().parse("ja").unwrap(), Month::January);
+    ///
+    /// let parser = MonthParser::new().min_abbrev_len(3);
+    /// assert!(parser.parse("ja").is_err());
+    /// assert_eq!(parser.parse("jan").unwrap(), Month::January);
+    /// ```
+    #[must_use]
+    pub const fn min_abbrev_len(mut self, len: usize) -> Self {
+        self.min_abbrev_len = len;
+        self
+    }
+
+    /// Choose which month wins when several are equally similar to the input
+    /// (default: [`TieBreak::Latest`])
+    ///
+    /// Ties only matter for short or lenient inputs, such as `"ju"` with a
+    /// low threshold, which is as close to June as it is to July.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fuzzymonth::{Month, MonthParser, TieBreak};
+    ///
+    /// let parser = MonthParser::new().threshold(0.5);
+    /// assert_eq!(parser.parse("ju").unwrap(), Month::July);
+    ///
+    /// let parser = parser.tie_break(TieBreak::Earliest);
+    /// assert_eq!(parser.parse("ju").unwrap(), Month::June);
+    /// ```
+    #[must_use]
+    pub const fn tie_break(mut self, tie_break: TieBreak) -> Self {
+        self.tie_break = tie_break;
+        self
+    }
+
+    /// Reject inputs longer than `len` characters (default: 64)
+    ///
+    /// Fuzzy matching takes time proportional to the length of the input for
+    /// every month name, so very long inputs (e.g. a pasted document) are
+    /// rejected before any matching takes place. Surrounding whitespace is not
+    /// counted. No month name comes close to the default limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fuzzymonth::MonthParser;
+    ///
+    /// let input = "january ".repeat(100);
+    /// assert!(MonthParser::new().parse(&input).is_err());
+    ///
+    /// let parser = MonthParser::new().max_input_len(5);
+    /// assert!(parser.parse("january").is_err());
+    /// assert!(parser.parse("jan").is_ok());
+    /// ```
+    #[must_use]
+    pub const fn max_input_len(mut self, len: usize) -> Self {
+        self.max_input_len = len;
+        self
+    }
+
+    /// Recognize additional spellings, such as project-specific codenames
+    ///
+    /// The variants are matched exactly (ignoring case and accents, like the
+    /// built-in international variants) and score 1.0. They are checked
+    /// before any built-in spelling, so they take precedence over the
+    /// built-in tables where they overlap. Calling this again adds to the
+    /// variants registered before.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fuzzymonth::{parse_month, Month, MonthParser};
+    ///
+    /// assert!(parse_month("frosty").is_err());
+    ///
+    /// let parser = MonthParser::new().with_extra_variants(&[("frosty", Month::December)]);
+    /// assert_eq!(parser.parse("Frosty").unwrap(), Month::December);
+    /// assert_eq!(parser.parse("march").unwrap(), Month::March);
+    /// ```
+    #[must_use]
+    pub fn with_extra_variants(mut self, variants: &[(&str, Month)]) -> Self {
+        self.extra_variants
+            .extend(variants.iter().map(|(variant, month)| {
+                let mut folded = String::new();
+                normalize::fold_case_into(variant, &mut folded);
+                (folded, *month)
+            }));
+        self
+    }
+
+    /// Whether to accept month numbers such as `"3"`, `"03"` or `"1st"`
+    ///
+    /// Enabled by default. Disable it where a number should never be taken
+    /// for a month. This turns off every form that names a month by its
+    /// number: digits, spelled-out English numbers (`"third"`, `"month
+    /// three"`) and localized names with digits (`"3月"`, `"tháng 3"`). Names
+    /// and abbreviations are still recognized, including those that happen to
+    /// contain a number word (`"三月"`, `"tháng ba"`), and so are day-first
+    /// dates like `"1er janvier"`, where the number is the day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fuzzymonth::{Month, MonthParser};
+    ///
+    /// let parser = MonthParser::new().numeric(false);
+    /// assert!(parser.parse("3").is_err());
+    /// assert!(parser.parse("third").is_err());
+    /// assert_eq!(parser.parse("march").unwrap(), Month::March);
+    /// ```
+    #[must_use]
+    pub const fn numeric(mut self, numeric: bool) -> Self {
+        self.numeric = numeric;
+        self
+    }
+
+    /// Replace Cyrillic and Greek letters that look like Latin ones before
+    /// matching
+    ///
+    /// Disabled by default. Copy-pasted text sometimes contains homoglyphs,
+    /// like a Cyrillic `а` in `"Januаry"`, which look right but don't match.
+    /// Only input that also contains ASCII letters is changed, but this could
+    /// still turn a typo in a genuinely non-Latin name into a false match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fuzzymonth::{Month, MonthParser};
+    ///
+    /// let parser = MonthParser::new().homoglyphs(true);
+    /// assert_eq!(parser.parse("Janu\u{430}ry").unwrap(), Month::January);
+    /// ```
+    #[must_use]
+    pub const fn homoglyphs(mut self, homoglyphs: bool) -> Self {
+        self.homoglyphs = homoglyphs;
+        self
+    }
+
+    /// Cache the results of fuzzy matching for up to `capacity` recent inputs
+    ///
+    /// Disabled by default. Fuzzy matching is the expensive part of parsing,
+    /// so this pays off when the same misspellings come up again and again.
+    /// Results are cached by the lowercased input, and the least recently
+    /// used one is dropped when the cache is full. A capacity of 0 disables
+    /// the cache again.
+    ///
+    /// The parser can still be shared between threads. Clones start with an
+    /// empty cache of their own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fuzzymonth::{Month, MonthParser};
+    ///
+    /// let parser = MonthParser::new().cache(128);
+    /// assert_eq!(parser.parse("janurary").unwrap(), Month::January);
+    /// assert_eq!(parser.parse("Janurary").unwrap(), Month::January); // cached
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn cache(mut self, capacity: usize) -> Self {
+        self.cache = (capacity > 0).then(|| cache::FuzzyCache::new(capacity));
+        self
+    }
+
+    /// Parse a month from a string
+    ///
+    /// See [`parse_month`] for the supported formats.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` variant if the input is not a valid month.
+    pub fn parse(&self, value: &str) -> Result<Month, ValidationError> {
+        self.parse_with_score(value).map(|(month, _)| month)
+    }
+
+    /// Parse a month from a string, together with the confidence of the match
+    ///
+    /// See [`parse_month_with_score`] for details on the score.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` variant if the input is not a valid month.
+    pub fn parse_with_score(&self, value: &str) -> Result<(Month, f64), ValidationError> {
+        self.parse_detailed(value)
+            .map(|result| (result.month, result.score))
+    }
 
-use strsim::normalized_levenshtein;
+    /// Parse a month from a string, with details about how it was matched
+    ///
+    /// See [`parse_month_detailed`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` variant if the input is not a valid month.
+    pub fn parse_detailed(&self, value: &str) -> Result<MonthMatch, ValidationError> {
+        let mut result = self.parse_with_buffer(value, &mut String::new())?;
+        let trimmed = normalize::trim(value);
+        // Exact matches are found before leetspeak is decoded
+        result.normalized_input = if result.exact {
+            let mut folded = String::new();
+            normalize::fold_case_into(trimmed, &mut folded);
+            folded
+        } else {
+            normalize::fold_for_matching(trimmed).into_owned()
+        };
+        Ok(result)
+    }
+
+    /// Parse a month with details, using `buffer` as scratch space for the
+    /// lowercased input
+    ///
+    /// The [`MonthMatch::normalized_input`] is left empty.
+    fn parse_with_buffer(
+        &self,
+        value: &str,
+        buffer: &mut String,
+    ) -> Result<MonthMatch, ValidationError> {
+        // Control flow: exact matches (names, abbreviations, numbers and
+        // international variants) return early with a score of 1.0. Anything
+        // that gets past `match_exact` is never an exact form, so everything
+        // below is fuzzy matching only; there is no point in checking for
+        // exact spellings again after the fuzzy pass.
+        let trimmed = normalize::trim(value);
+        // Checked without counting all characters of a pathologically long
+        // input, and before anything is done with all of it
+        if trimmed.chars().nth(self.max_input_len).is_some() {
+            return Err(ValidationError::InvalidEnumValue(format!(
+                "Invalid month: input is longer than {} characters",
+                self.max_input_len
+            )));
+        }
+        let unconfused;
+        let trimmed = if self.homoglyphs {
+            unconfused = normalize::fold_homoglyphs(trimmed);
+            &*unconfused
+        } else {
+            trimmed
+        };
+        if let Some((_, month)) = self
+            .extra_variants
+            .iter()
+            .find(|(variant, _)| normalize::fold_chars(trimmed).eq(variant.chars()))
+        {
+            return Ok(MonthMatch::exact(*month, MatchKind::Custom));
+        }
+        if trimmed.chars().count() < self.min_abbrev_len && is_abbreviation(trimmed) {
+            return Err(invalid_month(value));
+        }
+        if let Some((month, kind)) = match_exact_with(trimmed, self.numeric) {
+            return Ok(MonthMatch::exact(month, kind));
+        }
+
+        // Only the fuzzy matching below needs a lowercased copy of the input,
+        // and only if it isn't lowercase already
+        let input = normalize::fold_for_matching_into(trimmed, buffer);
+
+        if !self.allow_ambiguous && AMBIGUOUS_INPUTS.contains(&input) {
+            return Err(invalid_month(value));
+        }
+
+        #[cfg(feature = "std")]
+        let result = self.cache.as_ref().map_or_else(
+            || self.match_fuzzy(input),
+            |cache| {
+                let settings = cache::Settings {
+                    threshold: self.threshold.to_bits(),
+                    phonetic: self.phonetic,
+                    tie_break: self.tie_break,
+                };
+                cache.get_or_insert_with(input, settings, || self.match_fuzzy(input))
+            },
+        );
+        #[cfg(not(feature = "std"))]
+        let result = self.match_fuzzy(input);
+        result.ok_or_else(|| invalid_month(value))
+    }
+
+    /// The best fuzzy (or phonetic) match of the lowercased input
+    fn match_fuzzy(&self, input: &str) -> Option<MonthMatch> {
+        // Names that are too long or too short to reach the threshold are
+        // skipped before computing the (more expensive) similarity
+        let input_len = input.chars().count();
+        let mut best_match: Option<(f64, Month)> = None;
+        for (name, month) in MONTH_NAMES.iter().filter(|(name, _)| {
+            comparable_lengths(input, name)
+                && self.metric.max_similarity(input_len, name.chars().count()) >= self.threshold
+        }) {
+            let similarity = self.metric.similarity(input, name);
+            // `total_cmp` would rank NaN above every other score, so a broken
+            // custom metric could hide a valid match
+            if similarity.is_nan() {
+                continue;
+            }
+            let better = best_match.is_none_or(|(best_similarity, best_month)| {
+                match similarity.total_cmp(&best_similarity) {
+                    core::cmp::Ordering::Greater => true,
+                    core::cmp::Ordering::Less => false,
+                    core::cmp::Ordering::Equal => self.tie_break.prefers(*month, best_month),
+                }
+            });
+            if better {
+                best_match = Some((similarity, *month));
+            }
+        }
+
+        if let Some((similarity, month)) = best_match {
+            if similarity >= self.threshold {
+                return Some(MonthMatch::fuzzy(month, similarity));
+            }
+        }
+
+        if self.phonetic {
+            if let Some((month, name)) = match_phonetic(input) {
+                return Some(MonthMatch::fuzzy(
+                    month,
+                    self.metric.similarity(input, name),
+                ));
+            }
+        }
+
+        None
+    }
+}
+
+/// Find the only month that sounds like `input`, with its English name
+fn match_phonetic(input: &str) -> Option<(Month, &'static str)> {
+    let code = phonetic::encode(input).filter(|code| code.len() >= 2)?;
+    let mut matches = MONTH_NAMES.iter().filter(|(name, _)| {
+        comparable_lengths(input, name) && phonetic::encode(name).as_ref() == Some(&code)
+    });
+    let (name, month) = matches.next()?;
+    if matches.next().is_some() {
+        return None;
+    }
+    Some((*month, name))
+}
+
+/// Parse a month from a string
+///
+/// This function attempts to parse a month from a string input.
+/// It first tries to match exact month names, then tries fuzzy matching
+/// to handle typos and abbreviations.
+///
+/// # Arguments
+///
+/// * `value` - The month name, as any string type such as `&str`, `String`
+///   or `Cow<str>`
+///
+/// # Returns
+///
+/// * `Ok(Month)` if the month was successfully parsed
+/// * `Err(ValidationError)` if the input was not a valid month
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::{parse_month, Month};
+///
+/// assert_eq!(parse_month("january").unwrap(), Month::January);
+/// assert_eq!(parse_month("feb").unwrap(), Month::February);
+/// assert_eq!(parse_month("sept").unwrap(), Month::September);
+/// ```
+///
+/// # Errors
+///
+/// Returns an `Err` variant if the input is not a valid month.
+pub fn parse_month(value: impl AsRef<str>) -> Result<Month, ValidationError> {
+    MonthParser::new().parse(value.as_ref())
+}
+
+/// Parse a month from a string, returning `None` on failure
+///
+/// A shorthand for `parse_month(value).ok()` for callers that don't need the
+/// error, e.g. to skip invalid entries with [`Iterator::filter_map`].
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::{parse_month_opt, Month};
+///
+/// let months: Vec<Month> = ["jan", "nope", "3"].into_iter().filter_map(parse_month_opt).collect();
+/// assert_eq!(months, [Month::January, Month::March]);
+/// ```
+#[must_use]
+pub fn parse_month_opt(value: &str) -> Option<Month> {
+    parse_month(value).ok()
+}
+
+/// Parse a month from a string, falling back to the most similar month
+///
+/// This never fails: if [`parse_month`] rejects the input, the best month of
+/// [`rank_months`] is returned instead, however dissimilar it is. The
+/// threshold and the rejection of ambiguous inputs are effectively disabled,
+/// so even garbage gives some month (January if nothing is similar at all,
+/// such as for an empty input). Only use it where any guess is better than
+/// none, like a forgiving search box.
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::{parse_month_lossy, Month};
+///
+/// assert_eq!(parse_month_lossy("marsh"), Month::March);
+/// assert_eq!(parse_month_lossy(""), Month::January);
+/// ```
+#[must_use]
+pub fn parse_month_lossy(value: &str) -> Month {
+    parse_month_opt(value).unwrap_or_else(|| rank_months(value)[0].0)
+}
+
+/// Parse a month from a byte slice
+///
+/// Valid UTF-8 (including plain ASCII) is parsed in place, exactly like
+/// [`parse_month`]. Invalid UTF-8 sequences are replaced with `U+FFFD`
+/// (the replacement character) instead of failing the whole input. A
+/// replaced byte never matches exactly, but it only counts as a single typo
+/// for fuzzy matching, so `b"m\xffrch"` is still March.
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::{parse_month_bytes, Month};
+///
+/// assert_eq!(parse_month_bytes(b"march").unwrap(), Month::March);
+/// assert_eq!(parse_month_bytes(b"  Feb ").unwrap(), Month::February);
+/// assert_eq!(parse_month_bytes(b"septembe\xff").unwrap(), Month::September);
+/// ```
+///
+/// # Errors
+///
+/// Returns an `Err` variant if the input is not a valid month.
+pub fn parse_month_bytes(value: &[u8]) -> Result<Month, ValidationError> {
+    parse_month(String::from_utf8_lossy(value))
+}
+
+/// Parse a month from a string, together with the confidence of the match
+///
+/// Works like [`parse_month`], but also returns a score between 0.0 and 1.0.
+/// Exact matches (names, abbreviations, numbers, international variants)
+/// score 1.0; fuzzy matches return their similarity to the English month
+/// name, which is at least the similarity threshold of 0.75.
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::{parse_month_with_score, Month};
+///
+/// assert_eq!(parse_month_with_score("march").unwrap(), (Month::March, 1.0));
+///
+/// let (month, score) = parse_month_with_score("marh").unwrap();
+/// assert_eq!(month, Month::March);
+/// assert!(score < 1.0);
+/// ```
+///
+/// # Errors
+///
+/// Returns an `Err` variant if the input is not a valid month.
+pub fn parse_month_with_score(value: &str) -> Result<(Month, f64), ValidationError> {
+    MonthParser::new().parse_with_score(value)
+}
+
+/// Parse a month from a string, with details about how it was matched
+///
+/// Works like [`parse_month_with_score`], but also tells whether the input was
+/// an exact spelling or had to be corrected by fuzzy matching, e.g. to log
+/// corrections. Note that a corrected input can still score 1.0, such as the
+/// leetspeak `"m4rch"`.
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::{parse_month_detailed, MatchKind, Month};
+///
+/// let exact = parse_month_detailed("january").unwrap();
+/// assert_eq!(exact.month, Month::January);
+/// assert!(exact.exact);
+/// assert_eq!(parse_month_detailed("03").unwrap().kind, MatchKind::Numeric);
+///
+/// let corrected = parse_month_detailed("janurary").unwrap();
+/// assert_eq!(corrected.month, Month::January);
+/// assert!(!corrected.exact);
+/// assert!(corrected.score < 1.0);
+///
+/// // The normalized input shows what was actually matched
+/// # #[cfg(feature = "lang-fr")]
+/// assert_eq!(parse_month_detailed("FÉVRIER ").unwrap().normalized_input, "fevrier");
+/// ```
+///
+/// # Errors
+///
+/// Returns an `Err` variant if the input is not a valid month.
+pub fn parse_month_detailed(value: &str) -> Result<MonthMatch, ValidationError> {
+    MonthParser::new().parse_detailed(value)
+}
+
+/// Parse a month from a string, together with the number of edits needed
+///
+/// Works like [`parse_month`], but also returns the Levenshtein distance
+/// between the (lowercased) input and the English name of the month, i.e. the
+/// number of characters that had to be inserted, deleted or substituted.
+/// Exact matches (names, abbreviations, numbers, international variants)
+/// need 0 edits. This complements [`parse_month_with_score`], whose score is
+/// the same distance relative to the length of the name.
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::{parse_month_edits, Month};
+///
+/// assert_eq!(parse_month_edits("march").unwrap(), (Month::March, 0));
+/// assert_eq!(parse_month_edits("marh").unwrap(), (Month::March, 1));
+/// assert_eq!(parse_month_edits("Sept.").unwrap(), (Month::September, 0));
+/// ```
+///
+/// # Errors
+///
+/// Returns an `Err` variant if the input is not a valid month.
+pub fn parse_month_edits(value: &str) -> Result<(Month, usize), ValidationError> {
+    let MonthMatch { month, exact, .. } = parse_month_detailed(value)?;
+    if exact {
+        return Ok((month, 0));
+    }
+    let input = normalize::fold_for_matching(normalize::trim(value));
+    let edits = levenshtein(&input, month.localized_name(Language::English));
+    Ok((month, edits))
+}
+
+/// Rank all twelve months by their similarity to the input, best first
+///
+/// Unlike [`parse_month_with_score`], no threshold is applied, so this always
+/// returns every month, e.g. to offer the top few as suggestions. An input
+/// that matches a month exactly (including numbers, abbreviations and
+/// international variants) gives that month a score of 1.0; all other scores
+/// are the [`similarity`] of the lowercased input to the English month name.
+/// Months with equal scores are kept in calendar order. Like in
+/// [`similarity_breakdown`], inputs longer than 64 characters score 0.0.
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::{rank_months, Month};
+///
+/// let ranked = rank_months("jun");
+/// assert_eq!(ranked.len(), 12);
+/// assert_eq!(ranked[0], (Month::June, 1.0));
+/// assert_eq!(ranked[1].0, Month::July);
+/// ```
+#[must_use]
+pub fn rank_months(value: &str) -> Vec<(Month, f64)> {
+    let mut ranked = similarity_breakdown(value).to_vec();
+    // A stable sort keeps ties in calendar order
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranked
+}
+
+/// The score of every month for the input, in calendar order
+///
+/// These are the scores that [`rank_months`] sorts, meant for debugging: an
+/// exact match (including numbers, abbreviations and international variants)
+/// scores 1.0, all other months the [`similarity`] of the lowercased input to
+/// their English name. Comparing them to the threshold of 0.75 (see
+/// [`MonthParser::threshold`]) shows why a borderline input matched or not.
+/// Inputs longer than 64 characters (the default of
+/// [`MonthParser::max_input_len`]) score 0.0 for every month without being
+/// compared.
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::{similarity_breakdown, Month};
+///
+/// let scores = similarity_breakdown("septmber");
+/// assert_eq!(scores[8], (Month::September, 8.0 / 9.0));
+/// assert!(scores.iter().all(|(_, score)| *score <= scores[8].1));
+/// ```
+#[must_use]
+pub fn similarity_breakdown(value: &str) -> [(Month, f64); 12] {
+    let trimmed = normalize::trim(value);
+    if is_too_long(trimmed) {
+        return Month::all().map(|month| (month, 0.0));
+    }
+    let exact = match_exact(trimmed);
+    let input = normalize::fold_for_matching(trimmed);
+    Month::all().map(|month| {
+        let score = if exact == Some(month) {
+            1.0
+        } else {
+            normalized_levenshtein(&input, MONTH_NAMES[month.index()].0)
+        };
+        (month, score)
+    })
+}
+
+/// Rank all twelve months by their similarity to the input, considering only
+/// the spellings of some languages
+///
+/// Like [`rank_months`], but each month is scored by its most similar name or
+/// abbreviation in one of `languages` (compared case- and accent-insensitively),
+/// e.g. for suggestions in the user's language. Months without any spelling
+/// in these languages (such as languages whose feature is disabled) score 0.0,
+/// and so do all months for inputs longer than 64 characters. Months with
+/// equal scores are kept in calendar order.
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::{rank_months_in, Language, Month};
+///
+/// let ranked = rank_months_in("mar", &[Language::English]);
+/// assert_eq!(ranked[0], (Month::March, 1.0));
+///
+/// # #[cfg(feature = "lang-fr")]
+/// # {
+/// let ranked = rank_months_in("mar", &[Language::French]);
+/// assert_eq!(ranked[0], (Month::March, 0.75)); // "mars"
+/// # }
+/// ```
+#[must_use]
+pub fn rank_months_in(value: &str, languages: &[Language]) -> Vec<(Month, f64)> {
+    let trimmed = normalize::trim(value);
+    if is_too_long(trimmed) {
+        return Month::all().map(|month| (month, 0.0)).to_vec();
+    }
+    let input = normalize::fold_for_matching(trimmed);
+    let mut ranked: Vec<(Month, f64)> = Month::all()
+        .into_iter()
+        .map(|month| {
+            let english = MONTH_NAMES
+                .iter()
+                .chain(ABBREVIATIONS)
+                .map(|(spelling, month)| (*spelling, *month, Language::English));
+            let score = english
+                .chain(lang::variants())
+                .chain(lang::abbreviations())
+                .filter(|(_, spelling_month, language)| {
+                    *spelling_month == month && languages.contains(language)
+                })
+                .map(|(spelling, _, _)| {
+                    let spelling: String = normalize::fold_chars(spelling).collect();
+                    normalized_levenshtein(&input, &spelling)
+                })
+                .fold(0.0, f64::max);
+            (month, score)
+        })
+        .collect();
+    // A stable sort keeps ties in calendar order
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranked
+}
+
+/// All months whose English name starts with a prefix, in calendar order
+///
+/// Meant for prefix-driven navigation, like jumping to a month by typing its
+/// first letter, rather than for parsing: `"j"` gives January, June and July,
+/// while `"o"` gives only October. The prefix is trimmed and compared
+/// case-insensitively, and an empty prefix gives all twelve months.
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::{months_starting_with, Month};
+///
+/// assert_eq!(months_starting_with("j"), [Month::January, Month::June, Month::July]);
+/// assert_eq!(months_starting_with("Ma"), [Month::March, Month::May]);
+/// assert!(months_starting_with("x").is_empty());
+/// ```
+#[must_use]
+pub fn months_starting_with(prefix: &str) -> Vec<Month> {
+    let mut prefix_folded = String::new();
+    normalize::fold_case_into(normalize::trim(prefix), &mut prefix_folded);
+    MONTH_NAMES
+        .iter()
+        .filter(|(name, _)| name.starts_with(prefix_folded.as_str()))
+        .map(|(_, month)| *month)
+        .collect()
+}
+
+/// Similarity of two strings, as used by fuzzy matching
+///
+/// This is the normalized Levenshtein similarity between 0.0 (nothing in
+/// common) and 1.0 (identical), counted in `char`s. Fuzzy matching compares
+/// it against a threshold of 0.75 (see [`MonthParser::threshold`]), so
+/// callers can use it to score or pre-filter candidates consistently with
+/// the parser. Note that the parser lowercases the input before comparing
+/// it to the lowercase month names, while this function compares `a` and `b`
+/// as they are.
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::similarity;
+///
+/// assert_eq!(similarity("march", "march"), 1.0);
+/// assert_eq!(similarity("marh", "march"), 0.8);
+/// ```
+#[must_use]
+pub fn similarity(a: &str, b: &str) -> f64 {
+    normalized_levenshtein(a, b)
+}
+
+/// Whether an input is long enough (or short enough) to be a typo of `name`
+///
+/// The shorter of the two must be at least half as long as the longer one.
+/// Without this guard, a short input such as `"ma"` could be fuzzily matched
+/// to a long month name such as `"september"` whenever the similarity
+/// threshold is lenient enough. Genuine abbreviations are not affected, as
+/// they are handled by exact matching before fuzzy matching takes place.
+fn comparable_lengths(input: &str, name: &str) -> bool {
+    let (input_len, name_len) = (input.chars().count(), name.chars().count());
+    2 * input_len.min(name_len) >= input_len.max(name_len)
+}
+
+/// Parse many months at once
+///
+/// Returns the same results as calling [`parse_month`] on every input, but
+/// reuses a single scratch buffer for the normalized input instead of
+/// allocating a new one per item.
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::{parse_months, Month};
+///
+/// let results = parse_months(["jan", "febuary", "xyz"]);
+/// assert_eq!(results[0], Ok(Month::January));
+/// assert_eq!(results[1], Ok(Month::February));
+/// assert!(results[2].is_err());
+/// ```
+pub fn parse_months<'a>(
+    inputs: impl IntoIterator<Item = &'a str>,
+) -> Vec<Result<Month, ValidationError>> {
+    let parser = MonthParser::new();
+    let mut buffer = String::new();
+    inputs
+        .into_iter()
+        .map(|value| {
+            parser
+                .parse_with_buffer(value, &mut buffer)
+                .map(|result| result.month)
+        })
+        .collect()
+}
+
+/// Parse a month from a string without fuzzy matching
+///
+/// Like [`parse_month`], but only accepts exact month names, abbreviations,
+/// numbers and international variants. Typos such as `"janurary"` are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::{parse_month_exact, Month};
+///
+/// assert_eq!(parse_month_exact("January").unwrap(), Month::January);
+/// # #[cfg(feature = "lang-es")]
+/// assert_eq!(parse_month_exact("enero").unwrap(), Month::January);
+/// assert!(parse_month_exact("janurary").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns an `Err` variant if the input is not an exactly spelled month.
+pub fn parse_month_exact(value: &str) -> Result<Month, ValidationError> {
+    match_exact(normalize::trim(value)).ok_or_else(|| invalid_month(value))
+}
+
+/// Whether a string is a properly spelled month
+///
+/// True for the inputs accepted by [`parse_month_exact`]: month names,
+/// abbreviations, numbers and international variants, but no typos. Meant
+/// for validation, where `"janurary"` should be rejected rather than corrected.
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::is_valid_month;
+///
+/// assert!(is_valid_month("january"));
+/// assert!(is_valid_month("03"));
+/// assert!(!is_valid_month("janurary"));
+/// ```
+#[must_use]
+pub fn is_valid_month(value: &str) -> bool {
+    parse_month_exact(value).is_ok()
+}
+
+/// Parse a month from its number, without any other matching
+///
+/// Only `"1"` to `"12"` and the zero-padded `"01"` to `"09"` are accepted,
+/// optionally surrounded by whitespace. This is stricter and faster than
+/// [`parse_month`] and meant for input that is known to be numeric, like a
+/// month column in a CSV file.
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::{parse_month_numeric, Month, ValidationError};
+///
+/// assert_eq!(parse_month_numeric("07").unwrap(), Month::July);
+/// assert!(matches!(parse_month_numeric("13"), Err(ValidationError::OutOfRange(_))));
+/// assert!(parse_month_numeric("jan").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns [`ValidationError::OutOfRange`] for numbers other than 1 to 12,
+/// and [`ValidationError::InvalidEnumValue`] for anything that is not a
+/// number in the accepted format, including numbers of three or more digits
+/// with a leading zero (both `"010"` and `"013"`).
+pub fn parse_month_numeric(value: &str) -> Result<Month, ValidationError> {
+    let digits = value.trim();
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid_month(value));
+    }
+    // At most a single leading zero is allowed ("09", but not "009" or "010"),
+    // no matter whether the number would be in range ("013")
+    if digits.len() > 2 && digits.starts_with('0') {
+        return Err(invalid_month(value));
+    }
+    digits
+        .parse::<u32>()
+        .ok()
+        .and_then(month_from_number)
+        .ok_or_else(|| {
+            ValidationError::OutOfRange(format!(
+                "Month out of range: {value}. Enter a number from 1 to 12"
+            ))
+        })
+}
+
+/// The month of an ISO 8601 date such as `"2024-03-15"`
+///
+/// The date has to be `YYYY-MM-DD` or `YYYY/MM/DD`, with a four-digit year,
+/// two-digit month and day, and the same separator twice, optionally
+/// surrounded by whitespace. The day has to exist in that month.
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::{month_from_iso_date, Month};
+///
+/// assert_eq!(month_from_iso_date("2024-03-15").unwrap(), Month::March);
+/// assert_eq!(month_from_iso_date("2024/12/01").unwrap(), Month::December);
+/// assert!(month_from_iso_date("15.03.2024").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns [`ValidationError::InvalidEnumValue`] if the input is not shaped
+/// like a date, and [`ValidationError::OutOfRange`] if the month or day
+/// doesn't exist.
+pub fn month_from_iso_date(value: &str) -> Result<Month, ValidationError> {
+    let invalid = || {
+        ValidationError::InvalidEnumValue(format!(
+            "Invalid date: {value}. Enter a date as YYYY-MM-DD or YYYY/MM/DD"
+        ))
+    };
+    let date = value.trim();
+    let separator = date
+        .chars()
+        .nth(4)
+        .filter(|c| matches!(c, '-' | '/'))
+        .ok_or_else(invalid)?;
+    let mut fields = date.split(separator);
+    let (Some(year), Some(month), Some(day), None) =
+        (fields.next(), fields.next(), fields.next(), fields.next())
+    else {
+        return Err(invalid());
+    };
+    let is_number = |field: &str, len| field.len() == len && is_ascii_number(field);
+    if !(is_number(year, 4) && is_number(month, 2) && is_number(day, 2)) {
+        return Err(invalid());
+    }
+
+    let month = parse_month_numeric(month)?;
+    let year: i32 = year.parse().map_err(|_| invalid())?;
+    let day: u8 = day.parse().map_err(|_| invalid())?;
+    if !(1..=month.days_in_month(year)).contains(&day) {
+        return Err(ValidationError::OutOfRange(format!(
+            "Day out of range: {value}. {} {year} has {} days",
+            month.name(),
+            month.days_in_month(year)
+        )));
+    }
+    Ok(month)
+}
+
+/// Parse a quarter label such as `"Q1"` or `"Quarter 4"` into its months
+///
+/// Returns the three calendar months of the quarter in order, so `"Q1"`
+/// gives January, February and March. The label is case-insensitive and may
+/// use `Q` or the word `quarter`, with or without a space before the number.
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::{parse_quarter, Month};
+///
+/// assert_eq!(
+///     parse_quarter("q3").unwrap(),
+///     [Month::July, Month::August, Month::September]
+/// );
+/// assert!(parse_quarter("Q5").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns [`ValidationError::OutOfRange`] for quarters other than 1 to 4,
+/// and [`ValidationError::InvalidEnumValue`] if the input is not a quarter.
+pub fn parse_quarter(value: &str) -> Result<[Month; 3], ValidationError> {
+    let invalid = || {
+        ValidationError::InvalidEnumValue(format!(
+            "Invalid quarter: {value}. Enter a quarter from Q1 to Q4"
+        ))
+    };
+    let trimmed = value.trim();
+    let number = ["quarter", "q"]
+        .iter()
+        .find_map(|prefix| strip_prefix_ignore_ascii_case(trimmed, prefix))
+        .map(str::trim_start)
+        .filter(|number| is_ascii_number(number))
+        .ok_or_else(invalid)?;
+    let first = number
+        .parse::<u32>()
+        .ok()
+        .filter(|quarter| (1..=4).contains(quarter))
+        .and_then(|quarter| month_from_number(quarter * 3 - 2))
+        .ok_or_else(|| {
+            ValidationError::OutOfRange(format!(
+                "Quarter out of range: {value}. Enter a quarter from Q1 to Q4"
+            ))
+        })?;
+    Ok([first, first.succ(), first.succ().succ()])
+}
+
+/// Parse a half-year label such as `"H1"` or `"second half"` into its months
+///
+/// Returns the six calendar months of the half in order, so `"H1"` gives
+/// January to June and `"H2"` July to December. Besides `H1`/`H2`, the
+/// labels `1H`/`2H` and `first half`/`second half` are accepted, all
+/// case-insensitive.
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::{parse_half, Month};
+///
+/// let months = parse_half("H2").unwrap();
+/// assert_eq!(months[0], Month::July);
+/// assert_eq!(months[5], Month::December);
+/// assert!(parse_half("H3").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns [`ValidationError::OutOfRange`] for halves other than 1 and 2,
+/// and [`ValidationError::InvalidEnumValue`] if the input is not a half-year.
+pub fn parse_half(value: &str) -> Result<[Month; 6], ValidationError> {
+    let invalid =
+        || ValidationError::InvalidEnumValue(format!("Invalid half-year: {value}. Enter H1 or H2"));
+    let trimmed = value.trim();
+    let mut words = trimmed.split_whitespace();
+    let number = match (words.next(), words.next(), words.next()) {
+        (Some(first), Some(half), None) if half.eq_ignore_ascii_case("half") => {
+            if first.eq_ignore_ascii_case("first") {
+                Some("1")
+            } else if first.eq_ignore_ascii_case("second") {
+                Some("2")
+            } else {
+                None
+            }
+        }
+        _ => strip_prefix_ignore_ascii_case(trimmed, "h")
+            .map(str::trim_start)
+            .or_else(|| strip_suffix_ignore_ascii_case(trimmed, "h").map(str::trim_end)),
+    }
+    .filter(|number| is_ascii_number(number))
+    .ok_or_else(invalid)?;
+    let first = number
+        .parse::<u32>()
+        .ok()
+        .filter(|half| (1..=2).contains(half))
+        .and_then(|half| month_from_number(half * 6 - 5))
+        .ok_or_else(|| {
+            ValidationError::OutOfRange(format!("Half-year out of range: {value}. Enter H1 or H2"))
+        })?;
+    let mut next = first;
+    Ok(core::array::from_fn(|_| {
+        let month = next;
+        next = next.succ();
+        month
+    }))
+}
+
+/// `value` without `prefix`, compared case-insensitively
+fn strip_prefix_ignore_ascii_case<'a>(value: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = value.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix)
+        .then(|| &value[prefix.len()..])
+}
+
+/// `value` without `suffix`, compared case-insensitively
+fn strip_suffix_ignore_ascii_case<'a>(value: &'a str, suffix: &str) -> Option<&'a str> {
+    let start = value.len().checked_sub(suffix.len())?;
+    let tail = value.get(start..)?;
+    tail.eq_ignore_ascii_case(suffix).then(|| &value[..start])
+}
+
+/// Whether `value` is a non-empty run of ASCII digits
+fn is_ascii_number(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Parse a month together with a year, e.g. `"Mar 2024"` or `"2024/03"`
+///
+/// The year is the first run of exactly four ASCII digits and may come before
+/// or after the month. Whatever remains after removing the year and any
+/// separators (whitespace, `-`, `/`, `.`, `,`) is parsed with [`parse_month`].
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::{parse_month_year, Month};
+///
+/// assert_eq!(parse_month_year("Mar 2024").unwrap(), (Month::March, 2024));
+/// assert_eq!(parse_month_year("2024/03").unwrap(), (Month::March, 2024));
+/// assert_eq!(parse_month_year("march-2024").unwrap(), (Month::March, 2024));
+/// ```
+///
+/// # Errors
+///
+/// Returns an `Err` variant if no four-digit year is found or if the rest of
+/// the input is not a valid month.
+pub fn parse_month_year(value: &str) -> Result<(Month, i32), ValidationError> {
+    let (year, span) = find_year(value).ok_or_else(|| {
+        ValidationError::InvalidEnumValue(format!(
+            "Invalid month and year: {value}. Enter a month and a four-digit year"
+        ))
+    })?;
+
+    let is_separator = |c: char| c.is_whitespace() || matches!(c, '-' | '/' | '.' | ',');
+    let before = value[..span.start].trim_end_matches(is_separator);
+    let after = value[span.end..].trim_start_matches(is_separator);
+    let month = match (before.is_empty(), after.is_empty()) {
+        (true, _) => after,
+        (false, true) => before,
+        (false, false) => return Err(invalid_month(value)),
+    };
+
+    Ok((parse_month(month)?, year))
+}
+
+/// Find the first run of exactly four ASCII digits and its byte range
+fn find_year(value: &str) -> Option<(i32, core::ops::Range<usize>)> {
+    let bytes = value.as_bytes();
+    let mut start = 0;
+    while start < bytes.len() {
+        let len = bytes[start..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        if len == 4 {
+            let span = start..start + len;
+            return value[span.clone()].parse().ok().map(|year| (year, span));
+        }
+        start += len.max(1);
+    }
+    None
+}
+
+/// Match a trimmed input against all exact forms of a month
+/// (English names and abbreviations, numbers, international variants)
+///
+/// This never allocates; the input does not need to be lowercased.
+fn match_exact(input: &str) -> Option<Month> {
+    match_exact_with(input, true).map(|(month, _)| month)
+}
+
+/// [`match_exact`] with the kind of match, optionally without taking numbers
+/// such as `"3"` or `"1st"` for the month number
+fn match_exact_with(input: &str, numeric: bool) -> Option<(Month, MatchKind)> {
+    // First try exact matches including abbreviations. The English tables are
+    // ASCII, so comparing case-insensitively avoids lowercasing the input.
+    if let Some((_, month)) = MONTH_NAMES
+        .iter()
+        .find(|(name, _)| input.eq_ignore_ascii_case(name))
+    {
+        return Some((*month, MatchKind::Name));
+    }
+    if let Some((_, month)) = ABBREVIATIONS
+        .iter()
+        .find(|(abbreviation, _)| input.eq_ignore_ascii_case(abbreviation))
+    {
+        return Some((*month, MatchKind::Abbreviation));
+    }
+
+    // Dates such as "1er janvier" name the month after the day, so they have
+    // to be recognized before the day is taken for the month number
+    if let Some(result) = match_day_month(input) {
+        return Some(result);
+    }
+
+    // For ordinal numbers (1st, 2nd, etc.) and plain numbers
+    if let Some(month) = leading_number(input)
+        .filter(|_| numeric)
+        .and_then(month_from_number)
+    {
+        return Some((month, MatchKind::Numeric));
+    }
+
+    if !numeric {
+        // Localized forms with digits (`"3月"`) are month numbers as well
+        if input.bytes().any(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        return match_variant(input).map(|month| (month, MatchKind::International));
+    }
+
+    if let Some(month) = match_number_word(input) {
+        return Some((month, MatchKind::Numeric));
+    }
+
+    if let Some(month) = lang::match_numbered(input) {
+        return Some((month, MatchKind::International));
+    }
+
+    // Then in the parsing logic, check international variants after exact matches:
+    match_variant(input).map(|month| (month, MatchKind::International))
+}
+
+/// Whether an input is an English or localized abbreviation rather than a
+/// full month name
+fn is_abbreviation(input: &str) -> bool {
+    if ABBREVIATIONS
+        .iter()
+        .any(|(abbreviation, _)| input.eq_ignore_ascii_case(abbreviation))
+    {
+        return true;
+    }
+    // Some localized abbreviations are spelled like a full name elsewhere
+    lang::abbreviations().any(|(abbreviation, _, _)| normalize::folded_eq(input, abbreviation))
+        && !MONTH_NAMES
+            .iter()
+            .any(|(name, _)| input.eq_ignore_ascii_case(name))
+        && !lang::variants().any(|(variant, _, _)| normalize::folded_eq(input, variant))
+}
+
+/// Match the month of a date written day first, such as `"1er janvier"`,
+/// `"3 de marzo"` or `"1º de enero de 2024"`
+///
+/// The day has to be followed by an ordinal indicator ([`DAY_SUFFIXES`]) or
+/// a preposition ([`DAY_PREPOSITIONS`]), which English dates never have, so
+/// that `"12 march"` keeps meaning December like any other leading number.
+/// The month has to be a word (not a number), and may be followed by a year.
+fn match_day_month(input: &str) -> Option<(Month, MatchKind)> {
+    let mut words = input.split_whitespace();
+    let day = words.next()?;
+    let digits = day.bytes().take_while(u8::is_ascii_digit).count();
+    if !(1..=2).contains(&digits) {
+        return None;
+    }
+    let suffix = &day[digits..];
+    if !suffix.is_empty() && !DAY_SUFFIXES.contains(&suffix) {
+        return None;
+    }
+
+    let is_preposition = |word: &str| {
+        DAY_PREPOSITIONS
+            .iter()
+            .any(|p| word.eq_ignore_ascii_case(p))
+    };
+    let mut month = words.next()?;
+    if is_preposition(month) {
+        month = words.next()?;
+    } else if suffix.is_empty() {
+        return None;
+    }
+    if !month.chars().any(char::is_alphabetic) {
+        return None;
+    }
+
+    // An optional year, e.g. "de 2024"
+    let mut year = words.next();
+    if year.is_some_and(is_preposition) {
+        year = words.next();
+    }
+    if year.is_some_and(|year| !is_ascii_number(year)) || words.next().is_some() {
+        return None;
+    }
+
+    match_exact_with(month, true)
+}
+
+/// The number at the start of an input, ignoring leading punctuation
+///
+/// The first run of ASCII digits is parsed, as long as it is only preceded by
+/// characters that are neither letters nor digits. This accepts noise such as
+/// `"#3"` or `"(03)"`, while `"jun3"` or `"sept 2"` are not numbers. A minus
+/// sign right before the digits makes it a negative number (`"-3"`), which is
+/// rejected. Text after the digits is ignored (`"3rd"`), but an input with
+/// several numbers such as `"3 and 4"` is rejected, as it is unclear which
+/// one is meant.
+fn leading_number(input: &str) -> Option<u32> {
+    let start = input.find(|c: char| c.is_alphanumeric())?;
+    if input[..start].ends_with(['-', '\u{2212}']) {
+        return None;
+    }
+    let rest = &input[start..];
+    let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+    if rest[digits..].bytes().any(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    rest[..digits].parse().ok()
+}
+
+/// The month with the given number, from 1 for January to 12 for December
+const fn month_from_number(number: u32) -> Option<Month> {
+    if !matches!(number, 1..=12) {
+        return None;
+    }
+    // Between 0 and 11 after the check above
+    #[allow(clippy::as_conversions)]
+    let index = (number - 1) as usize;
+    Some(Month::all()[index])
+}
+
+/// Match a spelled-out number such as "twelfth", "month one" or "the third"
+///
+/// Filler words are skipped; exactly one number word has to remain.
+fn match_number_word(input: &str) -> Option<Month> {
+    let mut words = input
+        .split_whitespace()
+        .filter(|word| !NUMBER_FILLERS.iter().any(|f| word.eq_ignore_ascii_case(f)));
+    let word = words.next()?;
+    if words.next().is_some() {
+        return None;
+    }
+    NUMBER_WORDS
+        .iter()
+        .find(|(number, _)| word.eq_ignore_ascii_case(number))
+        .map(|(_, month)| *month)
+}
+
+/// Look up an international variant or localized abbreviation
+#[cfg(feature = "std")]
+fn match_variant(input: &str) -> Option<Month> {
+    let table = variant_table();
+    table
+        .binary_search_by(|(variant, _)| variant.chars().cmp(normalize::fold_chars(input)))
+        .ok()
+        .map(|index| table[index].1)
+}
+
+/// Look up an international variant or localized abbreviation
+///
+/// Without `std` there is no `OnceLock` to cache the folded table in, so the
+/// static tables are scanned and folded on the fly instead (without allocating).
+#[cfg(not(feature = "std"))]
+fn match_variant(input: &str) -> Option<Month> {
+    lang::variants()
+        .chain(lang::abbreviations())
+        .find(|(variant, _, _)| normalize::folded_eq(input, variant))
+        .map(|(_, month, _)| month)
+}
+
+/// International variants and localized abbreviations, case-folded once on
+/// first use and sorted by spelling, so that lookups are a binary search
+#[cfg(feature = "std")]
+fn variant_table() -> &'static [(String, Month)] {
+    static TABLE: std::sync::OnceLock<Vec<(String, Month)>> = std::sync::OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table: Vec<(String, Month)> = lang::variants()
+            .chain(lang::abbreviations())
+            .map(|(variant, month, _)| (normalize::fold_case(variant), month))
+            .collect();
+        // Spellings shared between languages always map to the same month
+        table.sort_by(|a, b| a.0.cmp(&b.0));
+        table.dedup_by(|a, b| a.0 == b.0);
+        table
+    })
+}
+
+/// Whether a trimmed input is longer than the default [`MAX_INPUT_LEN`]
+///
+/// Checked without counting all characters of a pathologically long input.
+fn is_too_long(trimmed: &str) -> bool {
+    trimmed.chars().nth(MAX_INPUT_LEN).is_some()
+}
+
+/// Error for an input that could not be parsed as a month
+fn invalid_month(value: &str) -> ValidationError {
+    ValidationError::InvalidEnumValue(format!(
+        "Invalid month: {value}. Enter a month from January to December"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::borrow::Cow;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("january", Month::January)]
+    #[case("jan", Month::January)]
+    #[case("1", Month::January)]
+    #[case("01", Month::January)]
+    #[case("January", Month::January)]
+    #[case(" january ", Month::January)] // whitespace handling
+    #[case("JANUARY", Month::January)] // case handling
+    #[case("JaNuArY", Month::January)] // mixed case handling
+    #[case("SePt", Month::September)] // mixed case abbreviation
+    fn test_exact_matches(#[case] input: &str, #[case] expected: Month) {
+        assert_eq!(parse_month(input).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("janurary", Month::January)] // common misspelling
+    #[case("feburary", Month::February)] // common misspelling
+    #[case("febuary", Month::February)] // common misspelling
+    #[case("marh", Month::March)] // single char deletion
+    #[case("appril", Month::April)] // double consonant error
+    #[case("apryl", Month::April)] // phonetic match
+    #[case("agust", Month::August)] // missing letter
+    #[case("augst", Month::August)] // missing letter
+    #[case("septmber", Month::September)] // missing letter
+    #[case("sepetember", Month::September)] // extra letter
+    #[case("ocktober", Month::October)] // extra letter
+    #[case("novemeber", Month::November)] // letter transposition
+    #[case("deccember", Month::December)] // double consonant error
+    fn test_fuzzy_matches(#[case] input: &str, #[case] expected: Month) {
+        assert_eq!(parse_month(input).unwrap(), expected);
+    }
+
+    /// A metric without a length bound, to compare against
+    struct Unbounded<M>(M);
+
+    impl<M: SimilarityMetric> SimilarityMetric for Unbounded<M> {
+        fn similarity(&self, input: &str, name: &str) -> f64 {
+            self.0.similarity(input, name)
+        }
+    }
+
+    /// Levenshtein, counting how often the similarity is computed
+    struct Counting<'a>(&'a core::cell::Cell<usize>);
+
+    impl SimilarityMetric for Counting<'_> {
+        fn similarity(&self, input: &str, name: &str) -> f64 {
+            self.0.set(self.0.get() + 1);
+            Levenshtein.similarity(input, name)
+        }
+
+        fn max_similarity(&self, input_len: usize, name_len: usize) -> f64 {
+            Levenshtein.max_similarity(input_len, name_len)
+        }
+    }
+
+    /// Levenshtein, but NaN for the month names starting with a "j"
+    struct NanForJ;
+
+    impl SimilarityMetric for NanForJ {
+        fn similarity(&self, input: &str, name: &str) -> f64 {
+            if name.starts_with('j') {
+                f64::NAN
+            } else {
+                Levenshtein.similarity(input, name)
+            }
+        }
+    }
+
+    #[rstest]
+    #[case("marh", Some(Month::March))]
+    #[case("novemer", Some(Month::November))]
+    #[case("janury", None)]
+    fn test_nan_scores_are_skipped(#[case] input: &str, #[case] expected: Option<Month>) {
+        for tie_break in [TieBreak::Earliest, TieBreak::Latest, TieBreak::First] {
+            let parser = MonthParser::new().tie_break(tie_break).metric(NanForJ);
+            assert_eq!(parser.parse(input).ok(), expected, "{input}");
+        }
+    }
+
+    #[rstest]
+    #[case("marh", "  MARH. ")]
+    #[case("septmber", "SepTmber")]
+    #[case("march", "m4rch")]
+    #[case("janurary", "Janurary")]
+    #[case("xyz", "XYZ")]
+    fn test_clean_and_messy_inputs_match_alike(#[case] clean: &str, #[case] messy: &str) {
+        let parser = MonthParser::new();
+        assert_eq!(
+            parser.parse_with_score(clean).ok(),
+            parser.parse_with_score(messy).ok()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_cache_skips_fuzzy_matching() {
+        let count = core::cell::Cell::new(0);
+        let parser = MonthParser::new().cache(4).metric(Counting(&count));
+        let uncached = MonthParser::new();
+        for input in ["janurary", "JANURARY", "janurary", "xyz", "xyz"] {
+            assert_eq!(
+                parser.parse_detailed(input),
+                uncached.parse_detailed(input),
+                "{input}"
+            );
+        }
+        let computed = count.get();
+        assert!(computed > 0);
+
+        // Everything is cached now, including the failure
+        for input in ["janurary", "Janurary", "xyz"] {
+            parser.parse(input).unwrap_or(Month::January);
+        }
+        assert_eq!(count.get(), computed);
+
+        // Exact matches never reach the cache
+        assert_eq!(parser.parse("march"), Ok(Month::March));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_cache_is_not_shared_by_clones() {
+        let parser = MonthParser::new().cache(4);
+        assert_eq!(parser.parse("marh"), Ok(Month::March));
+        let strict = parser.clone().threshold(0.9);
+        assert!(strict.parse("marh").is_err());
+        assert_eq!(parser.parse("marh"), Ok(Month::March));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_cache_follows_reconfiguration() {
+        let parser = MonthParser::new().cache(8);
+        assert_eq!(parser.parse("marh"), Ok(Month::March));
+        let parser = parser.threshold(0.95);
+        assert!(parser.parse("marh").is_err());
+        let parser = parser.threshold(0.75);
+        assert_eq!(parser.parse("marh"), Ok(Month::March));
+
+        let parser = MonthParser::new().cache(8);
+        assert!(parser.parse("jenyooary").is_err());
+        let parser = parser.phonetic(true);
+        assert_eq!(parser.parse("jenyooary"), Ok(Month::January));
+
+        let parser = MonthParser::new().threshold(0.5).cache(8);
+        assert_eq!(parser.parse("ju"), Ok(Month::July));
+        let parser = parser.tie_break(TieBreak::Earliest);
+        assert_eq!(parser.parse("ju"), Ok(Month::June));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_cache_is_shared_between_threads() {
+        let parser = MonthParser::new().cache(4);
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for _ in 0..100 {
+                        assert_eq!(parser.parse("septmber"), Ok(Month::September));
+                        assert!(parser.parse("xyz").is_err());
+                    }
+                });
+            }
+        });
+    }
+
+    #[test]
+    fn test_length_bound_gives_identical_results() {
+        let inputs = [
+            "janurary",
+            "feburary",
+            "febuary",
+            "marh",
+            "appril",
+            "apryl",
+            "agust",
+            "augst",
+            "septmber",
+            "sepetember",
+            "ocktober",
+            "novemeber",
+            "deccember",
+            "ju",
+            "ma",
+            "mayy",
+            "jully",
+            "marchhhh",
+            "decembr",
+            "xyz",
+            "",
+        ];
+        let keyboard = KeyboardDistance::new(KeyboardLayout::QWERTY);
+        for threshold in [0.0, 0.5, 0.6, 0.75, 0.8, 0.9, 1.0] {
+            let parser = MonthParser::new().threshold(threshold);
+            let unbounded = parser.clone().metric(Unbounded(Levenshtein));
+            let keyboard_parser = parser.clone().metric(keyboard);
+            let keyboard_unbounded = parser.clone().metric(Unbounded(keyboard));
+            for input in inputs {
+                assert_eq!(
+                    parser.parse_with_score(input),
+                    unbounded.parse_with_score(input),
+                    "{input} at {threshold}"
+                );
+                assert_eq!(
+                    keyboard_parser.parse_with_score(input),
+                    keyboard_unbounded.parse_with_score(input),
+                    "{input} at {threshold} with keyboard distance"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_length_bound_skips_similarity() {
+        let count = core::cell::Cell::new(0);
+        let parser = MonthParser::new().metric(Counting(&count));
+        assert_eq!(parser.parse("marchh"), Ok(Month::March));
+        // Only the names with 5 to 8 characters can reach 0.75
+        assert_eq!(count.get(), 8);
+
+        count.set(0);
+        let parser = parser.threshold(0.0);
+        assert!(parser.parse("marchh").is_ok());
+        assert_eq!(count.get(), 12);
+    }
+
+    #[rstest]
+    #[case("ma", "may", true)]
+    #[case("ma", "march", false)]
+    #[case("ma", "september", false)]
+    #[case("sep", "september", false)]
+    #[case("septembr", "september", true)]
+    #[case("xxxjanuaryyyy", "january", true)]
+    #[case("xxxmayyyy", "may", false)]
+    fn test_comparable_lengths(#[case] input: &str, #[case] name: &str, #[case] expected: bool) {
+        assert_eq!(comparable_lengths(input, name), expected);
+    }
+
+    #[rstest]
+    #[case("ma")]
+    #[case("se")]
+    #[case("no")]
+    #[case("a")]
+    fn test_short_inputs_are_rejected(#[case] input: &str) {
+        assert!(parse_month(input).is_err());
+    }
+
+    #[test]
+    fn test_french_des_is_rejected() {
+        // Neither the Norwegian nor the Indonesian abbreviation of December
+        assert!(parse_month("des").is_err());
+        assert!(parse_month("Des").is_err());
+        assert!(!is_abbreviation("des"));
+    }
+
+    #[rstest]
+    #[case("septembr", Month::September)]
+    #[case("mayy", Month::May)]
+    #[case("ju1y", Month::July)]
+    fn test_length_guard_keeps_typos(#[case] input: &str, #[case] expected: Month) {
+        assert_eq!(parse_month(input).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("ja", Month::January)] // partial match
+    #[case("feb", Month::February)] // partial match
+    #[case("sept", Month::September)] // partial match
+    #[case("nov", Month::November)] // partial match
+    #[case("dec", Month::December)] // partial match
+    fn test_abbreviated_inputs(#[case] input: &str, #[case] expected: Month) {
+        assert_eq!(parse_month(input).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("Jan.", Month::January)]
+    #[case("feb.", Month::February)]
+    #[case("Sept.", Month::September)]
+    #[case("sep.", Month::September)]
+    #[case(" oct. ", Month::October)]
+    fn test_period_terminated_abbreviations(#[case] input: &str, #[case] expected: Month) {
+        assert_eq!(parse_month(input).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("marsh")]
+    #[case("julie")]
+    #[case("januori")]
+    fn test_ambiguous_inputs_are_rejected_by_default(#[case] input: &str) {
+        assert!(parse_month(input).is_err());
+        assert!(MonthParser::new().parse(input).is_err());
+        assert!(MonthParser::default().parse(input).is_err());
+    }
+
+    #[test]
+    fn test_allow_ambiguous() {
+        let parser = MonthParser::new().allow_ambiguous(true);
+        assert_eq!(parser.parse("marsh").unwrap(), Month::March);
+        assert_eq!(parser.parse("MARSH").unwrap(), Month::March);
+        // Still not similar enough to any month
+        assert!(parser.parse("julie").is_err());
+        assert!(parser.parse("januori").is_err());
+        // Everything else behaves as before
+        assert_eq!(parser.parse("january").unwrap(), Month::January);
+        assert!(parser.parse("xyz").is_err());
+    }
+
+    /// Every spelling of the former exact-match block after the fuzzy pass
+    /// is handled by `match_exact`, so that block could never be reached
+    #[test]
+    fn test_exact_forms_never_reach_fuzzy_matching() {
+        let forms: &[(&[&str], Month)] = &[
+            (&["january", "jan", "1", "01"], Month::January),
+            (&["february", "feb", "2", "02"], Month::February),
+            (&["march", "mar", "3", "03"], Month::March),
+            (&["april", "apr", "4", "04"], Month::April),
+            (&["may", "5", "05"], Month::May),
+            (&["june", "jun", "6", "06"], Month::June),
+            (&["july", "jul", "7", "07"], Month::July),
+            (&["august", "aug", "8", "08"], Month::August),
+            (&["september", "sep", "sept", "9", "09"], Month::September),
+            (&["october", "oct", "10"], Month::October),
+            (&["november", "nov", "11"], Month::November),
+            (&["december", "dec", "12"], Month::December),
+        ];
+        for (spellings, month) in forms {
+            for spelling in *spellings {
+                assert_eq!(match_exact(spelling), Some(*month));
+                assert_eq!(parse_month_with_score(spelling), Ok((*month, 1.0)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_rank_months() {
+        let ranked = rank_months("sep");
+        assert_eq!(ranked.len(), 12);
+        assert_eq!(ranked[0], (Month::September, 1.0));
+        assert!(ranked.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+
+        let mut months: Vec<u8> = ranked.iter().map(|(month, _)| month.number()).collect();
+        months.sort_unstable();
+        assert_eq!(months, (1..=12).collect::<Vec<_>>());
+    }
+
+    #[rstest]
+    #[case("septmber", Month::September)]
+    #[case("9", Month::September)]
+    #[case("Jan.", Month::January)]
+    #[case("Marh", Month::March)]
+    fn test_rank_months_agrees_with_parse_month(#[case] input: &str, #[case] expected: Month) {
+        let ranked = rank_months(input);
+        assert_eq!(ranked[0].0, expected);
+        assert_eq!(ranked[0], parse_month_with_score(input).unwrap());
+    }
+
+    #[test]
+    fn test_rank_months_without_match() {
+        let ranked = rank_months("");
+        assert_eq!(ranked.len(), 12);
+        assert!(ranked.iter().all(|(_, score)| *score == 0.0));
+        // Ties are kept in calendar order
+        assert_eq!(ranked[0].0, Month::January);
+        assert_eq!(ranked[11].0, Month::December);
+    }
+
+    #[rstest]
+    #[case("march", "march", 1.0)]
+    #[case("marh", "march", 0.8)]
+    #[case("septmber", "september", 1.0 - 1.0 / 9.0)]
+    #[case("xyz", "march", 0.0)]
+    fn test_similarity(#[case] a: &str, #[case] b: &str, #[case] expected: f64) {
+        assert!((similarity(a, b) - expected).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_similarity_breakdown() {
+        let scores = similarity_breakdown("mar");
+        for (index, (month, score)) in scores.into_iter().enumerate() {
+            assert_eq!(month.index(), index);
+            if month != Month::March {
+                assert!(score < scores[Month::March.index()].1, "{month:?}");
+            }
+        }
+        // Without the abbreviation, "may" would be closer than "march"
+        assert!((scores[Month::May.index()].1 - 2.0 / 3.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_long_inputs_are_not_ranked() {
+        let input = format!("a{}", "0".repeat(80_000));
+        assert!(similarity_breakdown(&input)
+            .iter()
+            .all(|(_, score)| *score == 0.0));
+        assert!(rank_months(&input).iter().all(|(_, score)| *score == 0.0));
+        assert!(rank_months_in(&input, &[Language::English])
+            .iter()
+            .all(|(_, score)| *score == 0.0));
+        assert_eq!(parse_month_lossy(&input), Month::January);
+        assert!(parse_month_edits(&input).is_err());
+
+        let input = "marh".repeat(16);
+        assert!(similarity_breakdown(&input)[Month::March.index()].1 > 0.0);
+    }
+
+    #[test]
+    fn test_rank_months_in_english() {
+        let ranked = rank_months_in("Jun", &[Language::English]);
+        assert_eq!(ranked.len(), 12);
+        assert_eq!(ranked[0], (Month::June, 1.0));
+        assert!(ranked[1].1 < 1.0);
+    }
+
+    #[test]
+    fn test_rank_months_in_without_languages() {
+        let ranked = rank_months_in("march", &[]);
+        assert_eq!(ranked.len(), 12);
+        assert!(ranked.iter().all(|(_, score)| *score == 0.0));
+        assert_eq!(ranked[0].0, Month::January);
+    }
+
+    #[test]
+    fn test_sept_is_september_in_english() {
+        assert_eq!(
+            rank_months_in("sept", &[Language::English])[0],
+            (Month::September, 1.0)
+        );
+        assert_eq!(
+            parse_month_detailed("sept").unwrap().kind,
+            MatchKind::Abbreviation
+        );
+        assert!(rank_months_in("set", &[Language::English])[0].1 < 1.0);
+    }
+
+    #[cfg(all(feature = "lang-fr", feature = "lang-it"))]
+    #[test]
+    fn test_sept_and_set_by_language() {
+        // An abbreviation of "septembre", not the number seven
+        assert_eq!(
+            rank_months_in("sept", &[Language::French])[0],
+            (Month::September, 1.0)
+        );
+        assert_eq!(
+            rank_months_in("set", &[Language::Italian])[0],
+            (Month::September, 1.0)
+        );
+        assert!(rank_months_in("set", &[Language::French])[0].1 < 1.0);
+    }
+
+    #[cfg(feature = "lang-fr")]
+    #[test]
+    fn test_rank_months_in_french() {
+        let ranked = rank_months_in("mar", &[Language::French]);
+        assert_eq!(ranked[0], (Month::March, 0.75));
+        assert_eq!(ranked[1].0, Month::May); // "mai"
+        let ranked = rank_months_in("FÉVRIER", &[Language::French]);
+        assert_eq!(ranked[0], (Month::February, 1.0));
+        let ranked = rank_months_in("mar", &[Language::French, Language::English]);
+        assert_eq!(ranked[0], (Month::March, 1.0));
+    }
+
+    // Intentionally permissive: every input gives some month
+    #[rstest]
+    #[case("march", Month::March)]
+    #[case("marsh", Month::March)] // ambiguous, rejected by `parse_month`
+    #[case("decmbr", Month::December)] // below the threshold
+    #[case("13", Month::January)] // out of range and not similar to any name
+    #[case("", Month::January)]
+    #[case("   ", Month::January)]
+    fn test_parse_month_lossy(#[case] input: &str, #[case] expected: Month) {
+        assert_eq!(parse_month_lossy(input), expected);
+    }
+
+    #[rstest]
+    #[case("2024-03-15", Month::March)]
+    #[case("2024/03/15", Month::March)]
+    #[case(" 1999-12-31 ", Month::December)]
+    #[case("2024-02-29", Month::February)] // leap year
+    fn test_month_from_iso_date(#[case] input: &str, #[case] expected: Month) {
+        assert_eq!(month_from_iso_date(input), Ok(expected));
+    }
+
+    #[rstest]
+    #[case("2024-13-01", true)]
+    #[case("2024-00-01", true)]
+    #[case("2023-02-29", true)] // not a leap year
+    #[case("2024-04-31", true)]
+    #[case("2024-03-00", true)]
+    #[case("2024-03", false)]
+    #[case("2024-3-15", false)]
+    #[case("24-03-15", false)]
+    #[case("2024-03/15", false)]
+    #[case("2024-03-15-01", false)]
+    #[case("2024.03.15", false)]
+    #[case("march 15, 2024", false)]
+    #[case("", false)]
+    fn test_month_from_iso_date_invalid(#[case] input: &str, #[case] out_of_range: bool) {
+        let result = month_from_iso_date(input);
+        if out_of_range {
+            assert!(
+                matches!(result, Err(ValidationError::OutOfRange(_))),
+                "{input}"
+            );
+        } else {
+            assert!(
+                matches!(result, Err(ValidationError::InvalidEnumValue(_))),
+                "{input}"
+            );
+        }
+    }
+
+    #[rstest]
+    #[case("j", &[Month::January, Month::June, Month::July])]
+    #[case("d", &[Month::December])]
+    #[case("O", &[Month::October])]
+    #[case(" jun ", &[Month::June])]
+    #[case("march", &[Month::March])]
+    #[case("marchh", &[])]
+    #[case("x", &[])]
+    fn test_months_starting_with(#[case] prefix: &str, #[case] expected: &[Month]) {
+        assert_eq!(months_starting_with(prefix), expected);
+    }
+
+    #[test]
+    fn test_months_starting_with_empty_prefix() {
+        assert_eq!(months_starting_with("").len(), 12);
+    }
+
+    #[test]
+    fn test_similarity_matches_parse_score() {
+        for input in ["janury", "febuary", "septmber", "marh"] {
+            let (month, score) = parse_month_with_score(input).unwrap();
+            let name = month.name().to_lowercase();
+            assert!((similarity(input, &name) - score).abs() < 1e-10);
+            assert!(score >= SIMILARITY_THRESHOLD);
+        }
+    }
+
+    #[test]
+    fn test_parse_month_opt() {
+        let inputs = ["January", "", "febuary", "13", "xyz", "12", "marh"];
+        let months: Vec<Month> = inputs.into_iter().filter_map(parse_month_opt).collect();
+        assert_eq!(
+            months,
+            [
+                Month::January,
+                Month::February,
+                Month::December,
+                Month::March
+            ]
+        );
+        for input in inputs {
+            assert_eq!(parse_month_opt(input), parse_month(input).ok());
+        }
+    }
+
+    #[rstest]
+    #[case("Q1", [Month::January, Month::February, Month::March])]
+    #[case("q2", [Month::April, Month::May, Month::June])]
+    #[case(" Q 3 ", [Month::July, Month::August, Month::September])]
+    #[case("quarter 4", [Month::October, Month::November, Month::December])]
+    #[case("Quarter4", [Month::October, Month::November, Month::December])]
+    fn test_parse_quarter(#[case] input: &str, #[case] expected: [Month; 3]) {
+        assert_eq!(parse_quarter(input), Ok(expected));
+        assert!(expected
+            .iter()
+            .all(|month| month.quarter() == expected[0].quarter()));
+    }
+
+    #[test]
+    fn test_index() {
+        assert_eq!(Month::January.index(), 0);
+        assert_eq!(Month::December.index(), 11);
+        assert_eq!(Month::from_index(0), Ok(Month::January));
+        assert_eq!(Month::from_index(11), Ok(Month::December));
+        for index in 0..12 {
+            assert_eq!(Month::from_index(index).unwrap().index(), index);
+        }
+    }
+
+    #[rstest]
+    #[case(12)]
+    #[case(usize::MAX)]
+    fn test_from_index_out_of_range(#[case] index: usize) {
+        assert!(matches!(
+            Month::from_index(index),
+            Err(ValidationError::OutOfRange(_))
+        ));
+    }
+
+    #[rstest]
+    #[case("H1", Month::January)]
+    #[case("h1", Month::January)]
+    #[case("1H", Month::January)]
+    #[case("First Half", Month::January)]
+    #[case("H2", Month::July)]
+    #[case(" 2h ", Month::July)]
+    #[case("H 2", Month::July)]
+    #[case("second  half", Month::July)]
+    fn test_parse_half(#[case] input: &str, #[case] first: Month) {
+        let months = parse_half(input).unwrap();
+        assert_eq!(months[0], first);
+        assert!(months.windows(2).all(|pair| pair[1] == pair[0].succ()));
+    }
+
+    #[test]
+    fn test_parse_half_h2() {
+        assert_eq!(
+            parse_half("H2"),
+            Ok([
+                Month::July,
+                Month::August,
+                Month::September,
+                Month::October,
+                Month::November,
+                Month::December
+            ])
+        );
+    }
+
+    #[rstest]
+    #[case("H3", true)]
+    #[case("H0", true)]
+    #[case("3H", true)]
+    #[case("", false)]
+    #[case("H", false)]
+    #[case("HH", false)]
+    #[case("third half", false)]
+    #[case("first half year", false)]
+    #[case("half", false)]
+    fn test_parse_half_invalid(#[case] input: &str, #[case] out_of_range: bool) {
+        let err = parse_half(input).unwrap_err();
+        assert_eq!(matches!(err, ValidationError::OutOfRange(_)), out_of_range);
+    }
+
+    #[rstest]
+    #[case("Q5")]
+    #[case("Q0")]
+    #[case("quarter 12")]
+    fn test_parse_quarter_out_of_range(#[case] input: &str) {
+        assert!(matches!(
+            parse_quarter(input),
+            Err(ValidationError::OutOfRange(_))
+        ));
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("Q")]
+    #[case("1")]
+    #[case("Q1x")]
+    #[case("quart 1")]
+    #[case("first quarter")]
+    #[case("Ö1")]
+    fn test_parse_quarter_invalid(#[case] input: &str) {
+        assert!(matches!(
+            parse_quarter(input),
+            Err(ValidationError::InvalidEnumValue(_))
+        ));
+    }
+
+    #[rstest]
+    #[case("1", Month::January)]
+    #[case("07", Month::July)]
+    #[case("09", Month::September)]
+    #[case("12", Month::December)]
+    #[case(" 3 ", Month::March)]
+    fn test_parse_month_numeric(#[case] input: &str, #[case] expected: Month) {
+        assert_eq!(parse_month_numeric(input), Ok(expected));
+    }
+
+    #[rstest]
+    #[case("0")]
+    #[case("00")]
+    #[case("13")]
+    #[case("100")]
+    #[case("99999999999999999999")]
+    fn test_parse_month_numeric_out_of_range(#[case] input: &str) {
+        assert!(matches!(
+            parse_month_numeric(input),
+            Err(ValidationError::OutOfRange(_))
+        ));
+    }
+
+    #[rstest]
+    #[case("jan")]
+    #[case("")]
+    #[case("3rd")]
+    #[case("-1")]
+    #[case("+1")]
+    #[case("009")]
+    #[case("010")]
+    #[case("013")]
+    #[case("0010")]
+    #[case("m4rch")]
+    fn test_parse_month_numeric_invalid(#[case] input: &str) {
+        assert!(matches!(
+            parse_month_numeric(input),
+            Err(ValidationError::InvalidEnumValue(_))
+        ));
+    }
+
+    #[rstest]
+    #[case("janry", 0.6, Some(Month::January))]
+    #[case("janry", 0.75, None)]
+    #[case("janury", 0.75, Some(Month::January))]
+    #[case("janury", 0.9, None)]
+    #[case("janury", 1.0, None)]
+    #[case("jan", 1.0, Some(Month::January))] // exact matches ignore the threshold
+    fn test_threshold(
+        #[case] input: &str,
+        #[case] threshold: f64,
+        #[case] expected: Option<Month>,
+    ) {
+        let parser = MonthParser::new().threshold(threshold);
+        assert_eq!(parser.parse(input).ok(), expected);
+    }
+
+    #[rstest]
+    #[case(Month::January)]
+    #[case(Month::April)]
+    #[case(Month::December)]
+    fn test_iter_from(#[case] start: Month) {
+        let iter = Month::iter_from(start);
+        assert_eq!(iter.len(), 12);
+        let months: Vec<Month> = iter.collect();
+        assert_eq!(months[0], start);
+        assert_eq!(months[11], start.pred());
+        assert!(months.windows(2).all(|pair| pair[0].succ() == pair[1]));
+    }
+
+    #[rstest]
+    #[case(Month::February, 2024, 29)]
+    #[case(Month::February, 2023, 28)]
+    #[case(Month::February, 1900, 28)]
+    #[case(Month::February, 2000, 29)]
+    #[case(Month::November, 2023, 30)]
+    #[case(Month::November, 2024, 30)]
+    #[case(Month::December, 2024, 31)]
+    fn test_last_day(#[case] month: Month, #[case] year: i32, #[case] day: u8) {
+        assert_eq!(month.last_day(year), (month, day));
+    }
+
+    #[rstest]
+    #[case(Month::January, '❄', '🌞')]
+    #[case(Month::April, '🌸', '🍂')]
+    #[case(Month::July, '🌞', '❄')]
+    #[case(Month::October, '🍂', '🌸')]
+    fn test_to_emoji(#[case] month: Month, #[case] northern: char, #[case] southern: char) {
+        assert_eq!(month.to_emoji(Hemisphere::Northern), northern);
+        assert_eq!(month.to_emoji(Hemisphere::Southern), southern);
+    }
+
+    #[rstest]
+    #[case(Month::January, Season::Winter, Season::Summer)]
+    #[case(Month::March, Season::Winter, Season::Summer)]
+    #[case(Month::April, Season::Spring, Season::Autumn)]
+    #[case(Month::June, Season::Spring, Season::Autumn)]
+    #[case(Month::July, Season::Summer, Season::Winter)]
+    #[case(Month::September, Season::Summer, Season::Winter)]
+    #[case(Month::October, Season::Autumn, Season::Spring)]
+    #[case(Month::December, Season::Autumn, Season::Spring)]
+    fn test_astronomical_season(
+        #[case] month: Month,
+        #[case] northern: Season,
+        #[case] southern: Season,
+    ) {
+        assert_eq!(month.astronomical_season(Hemisphere::Northern), northern);
+        assert_eq!(month.astronomical_season(Hemisphere::Southern), southern);
+    }
+
+    #[test]
+    fn test_astronomical_season_differs_from_meteorological() {
+        assert_eq!(Month::March.season(Hemisphere::Northern), Season::Spring);
+        assert_eq!(
+            Month::March.astronomical_season(Hemisphere::Northern),
+            Season::Winter
+        );
+        assert_eq!(Month::March.season(Hemisphere::Southern), Season::Autumn);
+        assert_eq!(
+            Month::March.astronomical_season(Hemisphere::Southern),
+            Season::Summer
+        );
+    }
+
+    #[test]
+    fn test_all_is_in_calendar_order() {
+        for (index, month) in Month::all().into_iter().enumerate() {
+            assert_eq!(month.index(), index);
+        }
+    }
+
+    #[test]
+    fn test_tables_agree_with_months() {
+        for (index, (month, name, number)) in MONTHS.into_iter().enumerate() {
+            assert_eq!(month.index(), index);
+            assert_eq!(usize::from(number), index + 1);
+            assert_eq!(Month::all()[index], month);
+            assert_eq!(month_from_number(number.into()), Some(month));
+            assert_eq!(month.name(), name);
+            assert_eq!(month.number(), number);
+            assert_eq!(
+                MONTH_NAMES[index],
+                (name.to_ascii_lowercase().as_str(), month)
+            );
+            assert_eq!(month.abbreviation(), &name[..3]);
+            assert_eq!(
+                ABBREVIATIONS.contains(&(name[..3].to_ascii_lowercase().as_str(), month)),
+                month != Month::May
+            );
+        }
+        assert_eq!(MONTH_NAMES.len(), MONTHS.len());
+        assert!(ABBREVIATIONS.contains(&("ja", Month::January)));
+        assert!(ABBREVIATIONS.contains(&("sept", Month::September)));
+    }
+
+    #[test]
+    #[allow(clippy::as_conversions)]
+    fn test_discriminants_are_calendar_numbers() {
+        assert_eq!(Month::January as u8, 1);
+        assert_eq!(Month::March as u8, 3);
+        assert_eq!(Month::December as u8, 12);
+        for (index, month) in Month::all().into_iter().enumerate() {
+            assert_eq!(usize::from(month as u8), index + 1);
+        }
+    }
+
+    #[test]
+    fn test_month_from_number() {
+        let expected = [
+            (1, Month::January),
+            (2, Month::February),
+            (3, Month::March),
+            (4, Month::April),
+            (5, Month::May),
+            (6, Month::June),
+            (7, Month::July),
+            (8, Month::August),
+            (9, Month::September),
+            (10, Month::October),
+            (11, Month::November),
+            (12, Month::December),
+        ];
+        for (number, month) in expected {
+            assert_eq!(month_from_number(number), Some(month));
+        }
+        for number in [0, 13, u32::MAX] {
+            assert_eq!(month_from_number(number), None);
+        }
+    }
+
+    #[rstest]
+    #[case(Month::January, 0, Month::January)]
+    #[case(Month::January, 1, Month::February)]
+    #[case(Month::November, 3, Month::February)]
+    #[case(Month::January, -1, Month::December)]
+    #[case(Month::March, -27, Month::December)]
+    #[case(Month::June, 120, Month::June)]
+    #[case(Month::December, i32::MAX, Month::July)]
+    #[case(Month::January, i32::MIN, Month::May)]
+    fn test_add_months(#[case] month: Month, #[case] months: i32, #[case] expected: Month) {
+        assert_eq!(month.add_months(months), expected);
+    }
+
+    #[rstest]
+    #[case(Month::November, 5, Month::December)]
+    #[case(Month::February, -5, Month::January)]
+    #[case(Month::March, 2, Month::May)]
+    #[case(Month::March, -2, Month::January)]
+    #[case(Month::December, 0, Month::December)]
+    #[case(Month::June, i32::MAX, Month::December)]
+    #[case(Month::June, i32::MIN, Month::January)]
+    fn test_saturating_add_months(
+        #[case] month: Month,
+        #[case] months: i32,
+        #[case] expected: Month,
+    ) {
+        assert_eq!(month.saturating_add_months(months), expected);
+    }
+
+    #[rstest]
+    #[case((Month::December, 2023), (Month::February, 2024), 2)]
+    #[case((Month::February, 2024), (Month::December, 2023), -2)]
+    #[case((Month::March, 2024), (Month::March, 2024), 0)]
+    #[case((Month::January, 2000), (Month::December, 2000), 11)]
+    #[case((Month::June, -1), (Month::June, 1), 24)]
+    #[case((Month::January, i32::MIN), (Month::January, i32::MAX), i32::MAX)]
+    fn test_months_between(
+        #[case] from: (Month, i32),
+        #[case] to: (Month, i32),
+        #[case] expected: i32,
+    ) {
+        assert_eq!(months_between(from, to), expected);
+    }
+
+    #[rstest]
+    #[case(Month::January, "Capricorn")]
+    #[case(Month::March, "Pisces")]
+    #[case(Month::July, "Cancer")]
+    #[case(Month::December, "Sagittarius")]
+    fn test_zodiac_sign(#[case] month: Month, #[case] expected: &str) {
+        assert_eq!(month.zodiac_sign(), expected);
+    }
+
+    #[test]
+    fn test_zodiac_signs_are_distinct() {
+        let signs = Month::all().map(Month::zodiac_sign);
+        for (index, sign) in signs.iter().enumerate() {
+            assert!(!signs[..index].contains(sign), "{sign}");
+        }
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(Month::default(), Month::January);
+    }
+
+    #[test]
+    fn test_as_ref() {
+        assert!(["january"].contains(&Month::January.as_ref()));
+        for month in MONTH_NAMES.iter().map(|(_, month)| *month) {
+            assert_eq!(month.as_ref(), month.name().to_ascii_lowercase());
+            assert_eq!(parse_month(month), Ok(month));
+        }
+    }
+
+    #[test]
+    fn test_try_from_and_from_str() {
+        fn via_try_from<T: for<'a> TryFrom<&'a str>>(value: &str) -> Option<T> {
+            T::try_from(value).ok()
+        }
+
+        assert_eq!(Month::try_from("sept"), Ok(Month::September));
+        assert!(matches!(
+            Month::try_from("xyz"),
+            Err(ValidationError::InvalidEnumValue(_))
+        ));
+        assert_eq!("janurary".parse::<Month>(), Ok(Month::January));
+        assert!("13".parse::<Month>().is_err());
+        assert_eq!(via_try_from::<Month>("dec"), Some(Month::December));
+    }
+
+    #[test]
+    fn test_eq_str() {
+        assert!(Month::May == "May");
+        assert!(Month::May == "MAY");
+        assert!(Month::May != "mai");
+        assert!(Month::September != "sept");
+        assert!(Month::June != "July");
+        assert_eq!(Month::March, *String::from("march"));
+    }
+
+    #[rstest]
+    #[case("january", Month::January, true, 1.0)]
+    #[case("Jan.", Month::January, true, 1.0)]
+    #[case("1", Month::January, true, 1.0)]
+    #[case("janurary", Month::January, false, 0.875)]
+    #[case("m4rch", Month::March, false, 1.0)] // corrected, but identical after decoding
+    fn test_parse_month_detailed(
+        #[case] input: &str,
+        #[case] month: Month,
+        #[case] exact: bool,
+        #[case] score: f64,
+    ) {
+        let result = parse_month_detailed(input).unwrap();
+        assert_eq!(result.month, month);
+        assert_eq!(result.exact, exact);
+        assert!((result.score - score).abs() < 1e-10);
+    }
+
+    #[rstest]
+    #[case("january", "january")]
+    #[case(" MÁRCH ", "march")]
+    #[case("Jan.", "jan")]
+    #[case(" 03 ", "03")]
+    #[case("JANURARY", "janurary")]
+    #[case("M4RCH.", "march")]
+    fn test_parse_month_detailed_normalized_input(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(
+            parse_month_detailed(input).unwrap().normalized_input,
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("03", MatchKind::Numeric)]
+    #[case("3rd", MatchKind::Numeric)]
+    #[case("the third", MatchKind::Numeric)]
+    #[case("March", MatchKind::Name)]
+    #[case("may", MatchKind::Name)] // also an abbreviation, but the name wins
+    #[case("jan", MatchKind::Abbreviation)]
+    #[case("Sept.", MatchKind::Abbreviation)]
+    #[case("15 de march", MatchKind::Name)]
+    #[case("janurary", MatchKind::Fuzzy)]
+    #[case("m4rch", MatchKind::Fuzzy)]
+    fn test_match_kind(#[case] input: &str, #[case] expected: MatchKind) {
+        let result = parse_month_detailed(input).unwrap();
+        assert_eq!(result.kind, expected);
+        assert_eq!(result.exact, expected != MatchKind::Fuzzy);
+    }
+
+    #[cfg(all(feature = "lang-es", feature = "lang-vi"))]
+    #[rstest]
+    #[case("enero")]
+    #[case("ene")]
+    #[case("3 de marzo")]
+    #[case("tháng 3")]
+    fn test_match_kind_international(#[case] input: &str) {
+        assert_eq!(
+            parse_month_detailed(input).unwrap().kind,
+            MatchKind::International
+        );
+    }
+
+    #[test]
+    fn test_match_kind_custom() {
+        let parser = MonthParser::new().with_extra_variants(&[("frosty", Month::December)]);
+        assert_eq!(
+            parser.parse_detailed("frosty").unwrap().kind,
+            MatchKind::Custom
+        );
+    }
+
+    #[test]
+    fn test_match_kind_phonetic() {
+        let parser = MonthParser::new().phonetic(true);
+        assert_eq!(
+            parser.parse_detailed("jenyooary").unwrap().kind,
+            MatchKind::Fuzzy
+        );
+    }
+
+    #[rstest]
+    #[case("march", Month::March, 0)]
+    #[case("mar", Month::March, 0)]
+    #[case("3", Month::March, 0)]
+    #[case("marh", Month::March, 1)]
+    #[case("MARH", Month::March, 1)]
+    #[case("m4rh", Month::March, 1)] // leetspeak is decoded first
+    #[case("septembr", Month::September, 1)]
+    #[case("febuary", Month::February, 1)]
+    #[case("novembar", Month::November, 1)]
+    #[case("decmbr", Month::December, 2)]
+    fn test_parse_month_edits(#[case] input: &str, #[case] month: Month, #[case] edits: usize) {
+        assert_eq!(parse_month_edits(input), Ok((month, edits)));
+    }
+
+    #[test]
+    fn test_max_input_len() {
+        let long = "a".repeat(1_000_000);
+        assert!(matches!(
+            parse_month(&long),
+            Err(ValidationError::InvalidEnumValue(message)) if message.len() < 100
+        ));
+        assert_eq!(parse_month(format!("  {}  ", "x".repeat(64))).ok(), None);
+        let padding = " ".repeat(100); // surrounding whitespace is not counted
+        assert_eq!(
+            parse_month(format!("{padding}september{padding}")),
+            Ok(Month::September)
+        );
+
+        let parser = MonthParser::new().max_input_len(5);
+        assert_eq!(parser.parse("march"), Ok(Month::March));
+        assert!(parser.parse("marchh").is_err());
+        assert_eq!(
+            MonthParser::new().max_input_len(1000).parse("marchh"),
+            Ok(Month::March)
+        );
+    }
+
+    #[test]
+    fn test_parse_month_string_types() {
+        let owned = String::from("july");
+        assert_eq!(parse_month(owned.clone()), Ok(Month::July));
+        assert_eq!(parse_month(&owned), Ok(Month::July));
+        assert_eq!(parse_month(Cow::Borrowed("july")), Ok(Month::July));
+        assert_eq!(parse_month(Cow::<str>::Owned(owned)), Ok(Month::July));
+    }
+
+    #[rstest]
+    #[case(b"march", Month::March)]
+    #[case(b"  Feb ", Month::February)]
+    #[case(b"12", Month::December)]
+    #[case(b"m\xffrch", Month::March)] // invalid UTF-8 counts as a typo
+    #[case(b"septembe\xff", Month::September)]
+    fn test_parse_month_bytes(#[case] input: &[u8], #[case] expected: Month) {
+        assert_eq!(parse_month_bytes(input), Ok(expected));
+    }
+
+    #[rstest]
+    #[case(b"")]
+    #[case(b"\xff\xfe")]
+    #[case(b"ma\xff")]
+    fn test_parse_month_bytes_invalid(#[case] input: &[u8]) {
+        assert!(parse_month_bytes(input).is_err());
+    }
+
+    #[test]
+    fn test_extra_variants() {
+        assert!(parse_month("frosty").is_err());
+
+        let parser = MonthParser::new().with_extra_variants(&[("frosty", Month::December)]);
+        assert_eq!(
+            parser.parse_with_score("frosty"),
+            Ok((Month::December, 1.0))
+        );
+        assert_eq!(parser.parse(" FROSTY "), Ok(Month::December));
+        assert!(parser.parse("frostyy").is_err()); // not fuzzy matched
+        assert_eq!(parser.parse("march"), Ok(Month::March));
+
+        // Extra variants take precedence and can be added repeatedly
+        let parser = parser.with_extra_variants(&[("mar", Month::May), ("bloom", Month::April)]);
+        assert_eq!(parser.parse("mar"), Ok(Month::May));
+        assert_eq!(parser.parse("bloom"), Ok(Month::April));
+        assert_eq!(parser.parse("frosty"), Ok(Month::December));
+    }
+
+    #[rstest]
+    #[case("ja", 0, Some(Month::January))]
+    #[case("ja", 3, None)]
+    #[case("JA", 3, None)]
+    #[case("jan", 3, Some(Month::January))]
+    #[case("sep", 4, None)]
+    #[case("sept", 4, Some(Month::September))]
+    #[case("may", 4, Some(Month::May))] // full names are not abbreviations
+    #[case("3", 3, Some(Month::March))]
+    fn test_min_abbrev_len(
+        #[case] input: &str,
+        #[case] len: usize,
+        #[case] expected: Option<Month>,
+    ) {
+        let parser = MonthParser::new().min_abbrev_len(len);
+        assert_eq!(parser.parse(input).ok(), expected);
+    }
+
+    #[rstest]
+    #[case("Janu\u{430}ry", Month::January)] // Cyrillic а
+    #[case("\u{41e}ct", Month::October)] // Cyrillic О
+    #[case("\u{39c}ay", Month::May)] // Greek Μ
+    #[case("Janu\u{430}rry", Month::January)] // and a typo
+    fn test_homoglyphs(#[case] input: &str, #[case] expected: Month) {
+        assert!(parse_month_exact(input).is_err());
+        let parser = MonthParser::new().homoglyphs(true);
+        assert_eq!(parser.parse(input), Ok(expected));
+        assert_eq!(parser.parse_detailed(input).unwrap().month, expected);
+    }
+
+    #[test]
+    fn test_homoglyphs_respect_max_input_len() {
+        let parser = MonthParser::new().homoglyphs(true).max_input_len(8);
+        assert_eq!(parser.parse("Janu\u{430}ry"), Ok(Month::January));
+        let input = "Janu\u{430}ry ".repeat(100);
+        assert!(matches!(
+            parser.parse(&input),
+            Err(ValidationError::InvalidEnumValue(message)) if message.contains("longer than 8")
+        ));
+    }
 
-/// Month of the year
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
-pub enum Month {
-    January,
-    February,
-    March,
-    April,
-    May,
-    June,
-    July,
-    August,
-    September,
-    October,
-    November,
-    December,
-}
-
-/// An array of international month variants for fuzzy matching
-/// (e.g. Spanish, French, German, Italian, Polish, Russian, Arabic, Chinese)
-/// This is by no means complete and we should add more variants if possible.
-const INTERNATIONAL_VARIANTS: &[(&str, Month)] = &[
-    // January
-    ("enero", Month::January),   // Spanish
-    ("janvier", Month::January), // French
-    ("januar", Month::January),  // German
-    ("gennaio", Month::January), // Italian
-    ("styczeń", Month::January), // Polish
-    ("январь", Month::January),  // Russian
-    ("يناير", Month::January),   // Arabic
-    ("一月", Month::January),    // Chinese
-    // February
-    ("febrero", Month::February),  // Spanish
-    ("février", Month::February),  // French
-    ("februar", Month::February),  // German
-    ("febbraio", Month::February), // Italian
-    ("luty", Month::February),     // Polish
-    ("февраль", Month::February),  // Russian
-    ("فبراير", Month::February),   // Arabic
-    ("二月", Month::February),     // Chinese
-    // March
-    ("marzo", Month::March),  // Spanish
-    ("mars", Month::March),   // French
-    ("märz", Month::March),   // German
-    ("marzo", Month::March),  // Italian
-    ("marzec", Month::March), // Polish
-    ("март", Month::March),   // Russian
-    ("مارس", Month::March),   // Arabic
-    ("三月", Month::March),   // Chinese
-    // April
-    ("abril", Month::April),    // Spanish
-    ("avril", Month::April),    // French
-    ("april", Month::April),    // German
-    ("aprile", Month::April),   // Italian
-    ("kwiecień", Month::April), // Polish
-    ("апрель", Month::April),   // Russian
-    ("أبريل", Month::April),    // Arabic
-    ("四月", Month::April),     // Chinese
-    // May
-    ("mayo", Month::May),   // Spanish
-    ("mai", Month::May),    // French
-    ("mai", Month::May),    // German
-    ("maggio", Month::May), // Italian
-    ("maj", Month::May),    // Polish
-    ("май", Month::May),    // Russian
-    ("مايو", Month::May),   // Arabic
-    ("五月", Month::May),   // Chinese
-    // June
-    ("junio", Month::June),    // Spanish
-    ("juin", Month::June),     // French
-    ("juni", Month::June),     // German
-    ("giugno", Month::June),   // Italian
-    ("czerwiec", Month::June), // Polish
-    ("июнь", Month::June),     // Russian
-    ("يونيو", Month::June),    // Arabic
-    ("六月", Month::June),     // Chinese
-    // July
-    ("julio", Month::July),   // Spanish
-    ("juillet", Month::July), // French
-    ("juli", Month::July),    // German
-    ("luglio", Month::July),  // Italian
-    ("lipiec", Month::July),  // Polish
-    ("июль", Month::July),    // Russian
-    ("يوليو", Month::July),   // Arabic
-    ("七月", Month::July),    // Chinese
-    // August
-    ("agosto", Month::August),   // Spanish
-    ("août", Month::August),     // French
-    ("august", Month::August),   // German
-    ("agosto", Month::August),   // Italian
-    ("sierpień", Month::August), // Polish
-    ("август", Month::August),   // Russian
-    ("أغسطس", Month::August),    // Arabic
-    ("八月", Month::August),     // Chinese
-    // September
-    ("septiembre", Month::September), // Spanish
-    ("septembre", Month::September),  // French
-    ("september", Month::September),  // German
-    ("settembre", Month::September),  // Italian
-    ("wrzesień", Month::September),   // Polish
-    ("сентябрь", Month::September),   // Russian
-    ("سبتمبر", Month::September),     // Arabic
-    ("九月", Month::September),       // Chinese
-    // October
-    ("octubre", Month::October),     // Spanish
-    ("octobre", Month::October),     // French
-    ("oktober", Month::October),     // German
-    ("ottobre", Month::October),     // Italian
-    ("październik", Month::October), // Polish
-    ("октябрь", Month::October),     // Russian
-    ("أكتوبر", Month::October),      // Arabic
-    ("十月", Month::October),        // Chinese
-    // November
-    ("noviembre", Month::November), // Spanish
-    ("novembre", Month::November),  // French
-    ("november", Month::November),  // German
-    ("novembre", Month::November),  // Italian
-    ("listopad", Month::November),  // Polish
-    ("ноябрь", Month::November),    // Russian
-    ("نوفمبر", Month::November),    // Arabic
-    ("十一月", Month::November),    // Chinese
-    // December
-    ("diciembre", Month::December), // Spanish
-    ("décembre", Month::December),  // French
-    ("dezember", Month::December),  // German
-    ("dicembre", Month::December),  // Italian
-    ("grudzień", Month::December),  // Polish
-    ("декабрь", Month::December),   // Russian
-    ("ديسمبر", Month::December),    // Arabic
-    ("十二月", Month::December),    // Chinese
-];
+    #[cfg(feature = "lang-ru")]
+    #[test]
+    fn test_homoglyphs_keep_non_latin_names() {
+        let parser = MonthParser::new().homoglyphs(true);
+        assert_eq!(parser.parse("март"), Ok(Month::March));
+        assert_eq!(parser.parse("Сентябрь"), Ok(Month::September));
+    }
 
-/// Required similarity threshold for fuzzy matching to accept a date input
-///
-/// This is a lower threshold for more lenient matching
-///
-/// This is set on a best-effort basis based on testing
-const SIMILARITY_THRESHOLD: f64 = 0.75;
+    #[rstest]
+    #[case("3", Some(Month::March), None)]
+    #[case("03", Some(Month::March), None)]
+    #[case("1st", Some(Month::January), None)]
+    #[case("#12", Some(Month::December), None)]
+    #[case("march", Some(Month::March), Some(Month::March))]
+    #[case("third", Some(Month::March), None)]
+    #[case("month three", Some(Month::March), None)]
+    #[case("m4rch", Some(Month::March), Some(Month::March))]
+    fn test_numeric(
+        #[case] input: &str,
+        #[case] numeric: Option<Month>,
+        #[case] non_numeric: Option<Month>,
+    ) {
+        assert_eq!(MonthParser::new().parse(input).ok(), numeric);
+        let parser = MonthParser::new().numeric(false);
+        assert_eq!(parser.parse(input).ok(), non_numeric);
+    }
 
-/// Error type for validation errors
-/// (e.g. invalid enum value)
-#[derive(Debug, PartialEq, Eq)]
-pub enum ValidationError {
-    InvalidEnumValue(String),
-}
+    #[cfg(all(feature = "lang-ja", feature = "lang-vi", feature = "lang-fr"))]
+    #[rstest]
+    #[case("3月", None)]
+    #[case("tháng 3", None)]
+    #[case("三月", Some(Month::March))]
+    #[case("tháng ba", Some(Month::March))]
+    #[case("1er janvier", Some(Month::January))]
+    fn test_numeric_localized(#[case] input: &str, #[case] non_numeric: Option<Month>) {
+        assert!(MonthParser::new().parse(input).is_ok());
+        let parser = MonthParser::new().numeric(false);
+        assert_eq!(parser.parse(input).ok(), non_numeric);
+    }
 
-/// Map from month name to Month enum variant
-const MONTH_NAMES: &[(&str, Month)] = &[
-    ("january", Month::January),
-    ("february", Month::February),
-    ("march", Month::March),
-    ("april", Month::April),
-    ("may", Month::May),
-    ("june", Month::June),
-    ("july", Month::July),
-    ("august", Month::August),
-    ("september", Month::September),
-    ("october", Month::October),
-    ("november", Month::November),
-    ("december", Month::December),
-];
+    #[rstest]
+    #[case(TieBreak::Earliest, Month::June)]
+    #[case(TieBreak::Latest, Month::July)]
+    #[case(TieBreak::First, Month::June)]
+    fn test_tie_break(#[case] tie_break: TieBreak, #[case] expected: Month) {
+        let parser = MonthParser::new().threshold(0.5).tie_break(tie_break);
+        assert_eq!(parser.parse_with_score("ju"), Ok((expected, 0.5)));
+    }
 
-/// Parse a month from a string
-///
-/// This function attempts to parse a month from a string input.
-/// It first tries to match exact month names, then tries fuzzy matching
-/// to handle typos and abbreviations.
-///
-/// # Arguments
-///
-/// * `value` - A string slice containing the month name
-///
-/// # Returns
-///
-/// * `Ok(Month)` if the month was successfully parsed
-/// * `Err(ValidationError)` if the input was not a valid month
-///
-/// # Examples
-///
-/// ```
-/// use fuzzymonth::{parse_month, Month};
-///
-/// assert_eq!(parse_month("january").unwrap(), Month::January);
-/// assert_eq!(parse_month("feb").unwrap(), Month::February);
-/// assert_eq!(parse_month("sept").unwrap(), Month::September);
-/// ```
-///
-/// # Errors
-///
-/// Returns an `Err` variant if the input is not a valid month.
-pub fn parse_month(value: &str) -> Result<Month, ValidationError> {
-    let input = value.trim().to_lowercase();
-
-    // First try exact matches including abbreviations
-    match input.as_str() {
-        "january" | "jan" | "ja" | "1" | "01" => return Ok(Month::January),
-        "february" | "feb" | "2" | "02" => return Ok(Month::February),
-        "march" | "mar" | "3" | "03" => return Ok(Month::March),
-        "april" | "apr" | "4" | "04" => return Ok(Month::April),
-        "may" | "5" | "05" => return Ok(Month::May),
-        "june" | "jun" | "6" | "06" => return Ok(Month::June),
-        "july" | "jul" | "7" | "07" => return Ok(Month::July),
-        "august" | "aug" | "8" | "08" => return Ok(Month::August),
-        "september" | "sep" | "sept" | "9" | "09" => return Ok(Month::September),
-        "october" | "oct" | "10" => return Ok(Month::October),
-        "november" | "nov" | "11" => return Ok(Month::November),
-        "december" | "dec" | "12" => return Ok(Month::December),
-        _ => {}
+    #[test]
+    fn test_tie_break_only_applies_to_ties() {
+        let parser = MonthParser::new().tie_break(TieBreak::Earliest);
+        assert_eq!(parser.parse("jully").unwrap(), Month::July);
+        assert_eq!(MonthParser::new().parse("ju"), parse_month("ju"));
     }
 
-    // For ordinal numbers (1st, 2nd, etc.) and plain numbers
-    if let Ok(num) = input
-        .chars()
-        .take_while(char::is_ascii_digit)
-        .collect::<String>()
-        .parse::<u32>()
-    {
-        if (1..=12).contains(&num) {
-            return Ok(match num {
-                1 => Month::January,
-                2 => Month::February,
-                3 => Month::March,
-                4 => Month::April,
-                5 => Month::May,
-                6 => Month::June,
-                7 => Month::July,
-                8 => Month::August,
-                9 => Month::September,
-                10 => Month::October,
-                11 => Month::November,
-                12 => Month::December,
-                _ => unreachable!(),
-            });
-        }
+    #[rstest]
+    #[case("m4rch", Month::March)]
+    #[case("dec3mber", Month::December)]
+    #[case("0ct0b3r", Month::October)]
+    #[case("$eptember", Month::September)]
+    #[case("3", Month::March)]
+    #[case("03", Month::March)]
+    #[case("3rd", Month::March)]
+    fn test_leetspeak(#[case] input: &str, #[case] expected: Month) {
+        assert_eq!(parse_month(input), Ok(expected));
     }
 
-    // Then in the parsing logic, check international variants after exact matches:
-    for (variant, month) in INTERNATIONAL_VARIANTS {
-        if input == *variant {
-            return Ok(*month);
-        }
+    #[rstest]
+    #[case("mat", Month::May)]
+    #[case("janiaty", Month::January)]
+    #[case("nocenbet", Month::November)]
+    fn test_keyboard_distance_neighbor_typos(#[case] input: &str, #[case] expected: Month) {
+        assert!(parse_month(input).is_err());
+        let parser = MonthParser::new().metric(KeyboardDistance::new(KeyboardLayout::QWERTY));
+        assert_eq!(parser.parse(input), Ok(expected));
     }
 
-    match input.as_str() {
-        "marsh" | "julie" | "januori" => {
-            return Err(ValidationError::InvalidEnumValue(format!(
-                "Invalid month: {value}. Enter a month from January to December"
-            )));
-        }
-        _ => {}
+    #[rstest]
+    #[case("maq")]
+    #[case("janpaxy")]
+    #[case("nozenbex")]
+    fn test_keyboard_distance_other_typos(#[case] input: &str) {
+        let parser = MonthParser::new().metric(KeyboardDistance::default());
+        assert!(parser.parse(input).is_err());
     }
 
-    let best_match = MONTH_NAMES
-        .iter()
-        .map(|(name, month)| {
-            let similarity = normalized_levenshtein(&input, name);
-            (similarity, month)
-        })
-        .max_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Greater));
+    #[test]
+    fn test_keyboard_layout_is_configurable() {
+        let qwerty = MonthParser::new().metric(KeyboardDistance::new(KeyboardLayout::QWERTY));
+        let azerty = MonthParser::new().metric(KeyboardDistance::new(KeyboardLayout::AZERTY));
+        // "w" is next to "a" on QWERTY, but not on AZERTY
+        assert_eq!(qwerty.parse("mwy"), Ok(Month::May));
+        assert!(azerty.parse("mwy").is_err());
+    }
 
-    if let Some((similarity, month)) = best_match {
-        if similarity >= SIMILARITY_THRESHOLD {
-            return Ok(*month);
-        }
+    #[rstest]
+    #[case("jenyooary", Month::January)]
+    #[case("febyuwerry", Month::February)]
+    #[case("Sepptemmburr", Month::September)]
+    fn test_phonetic(#[case] input: &str, #[case] expected: Month) {
+        assert!(parse_month(input).is_err());
+        let (month, score) = MonthParser::new()
+            .phonetic(true)
+            .parse_with_score(input)
+            .unwrap();
+        assert_eq!(month, expected);
+        assert!(score < SIMILARITY_THRESHOLD);
     }
 
-    // Could also handle common typos explicitly:
-    match input.as_str() {
-        "january" | "jan" | "1" | "01" => return Ok(Month::January),
-        "february" | "feb" | "2" | "02" => return Ok(Month::February),
-        "march" | "mar" | "3" | "03" => return Ok(Month::March),
-        "april" | "apr" | "4" | "04" => return Ok(Month::April),
-        "may" | "5" | "05" => return Ok(Month::May),
-        "june" | "jun" | "6" | "06" => return Ok(Month::June),
-        "july" | "jul" | "7" | "07" => return Ok(Month::July),
-        "august" | "aug" | "8" | "08" => return Ok(Month::August),
-        "september" | "sep" | "sept" | "9" | "09" => return Ok(Month::September),
-        "october" | "oct" | "10" => return Ok(Month::October),
-        "november" | "nov" | "11" => return Ok(Month::November),
-        "december" | "dec" | "12" => return Ok(Month::December),
-        _ => {}
+    #[rstest]
+    #[case("me")]
+    #[case("bob")]
+    #[case("marsh")]
+    #[case("tomato")]
+    fn test_phonetic_invalid(#[case] input: &str) {
+        assert!(MonthParser::new().phonetic(true).parse(input).is_err());
     }
 
-    Err(ValidationError::InvalidEnumValue(format!(
-        "Invalid month: {value}. Enter a month from January to December"
-    )))
-}
+    #[test]
+    fn test_phonetic_keeps_fuzzy_score() {
+        let parser = MonthParser::new().phonetic(true);
+        assert_eq!(
+            parser.parse_with_score("Janury"),
+            parse_month_with_score("Janury")
+        );
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[rstest]
+    #[case("twelfth", Month::December)]
+    #[case("month one", Month::January)]
+    #[case("the third", Month::March)]
+    #[case("Second", Month::February)]
+    #[case("the seventh month", Month::July)]
+    fn test_number_words(#[case] input: &str, #[case] expected: Month) {
+        assert_eq!(parse_month_exact(input), Ok(expected));
+        assert_eq!(parse_month_with_score(input), Ok((expected, 1.0)));
+    }
 
-    use rstest::rstest;
-    use strsim::normalized_levenshtein;
+    #[rstest]
+    #[case("the month")]
+    #[case("one two")]
+    #[case("thirteenth")]
+    fn test_number_words_invalid(#[case] input: &str) {
+        assert!(parse_month_exact(input).is_err());
+    }
 
     #[rstest]
-    #[case("january", Month::January)]
-    #[case("jan", Month::January)]
-    #[case("1", Month::January)]
-    #[case("01", Month::January)]
-    #[case("January", Month::January)]
-    #[case(" january ", Month::January)] // whitespace handling
-    #[case("JANUARY", Month::January)] // case handling
-    fn test_exact_matches(#[case] input: &str, #[case] expected: Month) {
-        assert_eq!(parse_month(input).unwrap(), expected);
+    #[case("#3", Month::March)]
+    #[case("(03)", Month::March)]
+    #[case("[12]", Month::December)]
+    #[case("- 7 -", Month::July)]
+    fn test_numbers_with_noise(#[case] input: &str, #[case] expected: Month) {
+        assert_eq!(parse_month_exact(input), Ok(expected));
     }
 
     #[rstest]
-    #[case("janurary", Month::January)] // common misspelling
-    #[case("feburary", Month::February)] // common misspelling
-    #[case("febuary", Month::February)] // common misspelling
-    #[case("marh", Month::March)] // single char deletion
-    #[case("appril", Month::April)] // double consonant error
-    #[case("apryl", Month::April)] // phonetic match
-    #[case("agust", Month::August)] // missing letter
-    #[case("augst", Month::August)] // missing letter
-    #[case("septmber", Month::September)] // missing letter
-    #[case("sepetember", Month::September)] // extra letter
-    #[case("ocktober", Month::October)] // extra letter
-    #[case("novemeber", Month::November)] // letter transposition
-    #[case("deccember", Month::December)] // double consonant error
-    fn test_fuzzy_matches(#[case] input: &str, #[case] expected: Month) {
-        assert_eq!(parse_month(input).unwrap(), expected);
+    #[case("-3")]
+    #[case("\u{2212}3")]
+    #[case("(-12)")]
+    #[case("3 and 4")] // unclear which number is meant
+    #[case("3 4")]
+    #[case("1er 3")]
+    fn test_negative_and_multiple_numbers_are_rejected(#[case] input: &str) {
+        assert!(parse_month_exact(input).is_err());
+        assert!(parse_month(input).is_err());
     }
 
     #[rstest]
-    #[case("ja", Month::January)] // partial match
-    #[case("feb", Month::February)] // partial match
-    #[case("sept", Month::September)] // partial match
-    #[case("nov", Month::November)] // partial match
-    #[case("dec", Month::December)] // partial match
-    fn test_abbreviated_inputs(#[case] input: &str, #[case] expected: Month) {
-        assert_eq!(parse_month(input).unwrap(), expected);
+    #[case("jun3")] // letters before the digits, so not a number
+    #[case("sept 2")]
+    #[case("Q1")]
+    fn test_numbers_after_letters_are_ignored(#[case] input: &str) {
+        assert!(parse_month_exact(input).is_err());
     }
 
     #[rstest]
@@ -381,6 +3887,27 @@ mod tests {
         assert_eq!(parse_month(input).unwrap(), expected);
     }
 
+    #[rstest]
+    #[case("15 de march", Month::March)]
+    #[case("1º de may", Month::May)]
+    #[case("1er june", Month::June)]
+    #[case("31 del december de 2024", Month::December)]
+    #[case("12 march", Month::December)] // English dates start with the month number
+    fn test_day_month(#[case] input: &str, #[case] expected: Month) {
+        assert_eq!(parse_month_exact(input), Ok(expected));
+    }
+
+    #[rstest]
+    #[case("1er janvier", Month::January)]
+    #[case("3 de marzo", Month::March)]
+    #[case("15 de marzo", Month::March)]
+    #[case("1º de enero", Month::January)]
+    #[case("15 de marzo de 2024", Month::March)]
+    #[cfg(all(feature = "lang-fr", feature = "lang-es"))]
+    fn test_day_month_localized(#[case] input: &str, #[case] expected: Month) {
+        assert_eq!(parse_month(input).unwrap(), expected);
+    }
+
     #[rstest]
     #[case("januori")] // too different
     #[case("marsh")] // could be march but too ambiguous
@@ -427,11 +3954,146 @@ mod tests {
         }
     }
 
+    #[rstest]
+    #[case("march", Month::March, 1.0)]
+    #[case("03", Month::March, 1.0)]
+    #[case("marh", Month::March, 0.8)]
+    #[case("septmber", Month::September, 1.0 - 1.0 / 9.0)]
+    fn test_parse_month_with_score(#[case] input: &str, #[case] month: Month, #[case] score: f64) {
+        let (parsed, parsed_score) = parse_month_with_score(input).unwrap();
+        assert_eq!(parsed, month);
+        assert!((parsed_score - score).abs() < 1e-10);
+    }
+
+    #[rstest]
+    #[case("january", Month::January)]
+    #[case("Sept.", Month::September)]
+    #[case("03", Month::March)]
+    fn test_parse_month_exact(#[case] input: &str, #[case] expected: Month) {
+        assert_eq!(parse_month_exact(input).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("janurary")]
+    #[case("septmber")]
+    #[case("j@nuary")]
+    fn test_parse_month_exact_rejects_typos(#[case] input: &str) {
+        assert!(parse_month(input).is_ok());
+        assert!(matches!(
+            parse_month_exact(input),
+            Err(ValidationError::InvalidEnumValue(_))
+        ));
+    }
+
+    #[rstest]
+    #[case("january", true)]
+    #[case("Sept.", true)]
+    #[case("12", true)]
+    #[case("janurary", false)]
+    #[case("13", false)]
+    #[case("", false)]
+    fn test_is_valid_month(#[case] input: &str, #[case] expected: bool) {
+        assert_eq!(is_valid_month(input), expected);
+    }
+
+    #[rstest]
+    #[case("Mar 2024", Month::March, 2024)]
+    #[case("2024/03", Month::March, 2024)]
+    #[case("march-2024", Month::March, 2024)]
+    #[case("2024 march", Month::March, 2024)]
+    #[case("Sept. 1999", Month::September, 1999)]
+    #[case("12/2023", Month::December, 2023)]
+    #[case(" january, 2025 ", Month::January, 2025)]
+    #[case("septmber 2024", Month::September, 2024)] // typos are still corrected
+    fn test_parse_month_year(#[case] input: &str, #[case] month: Month, #[case] year: i32) {
+        assert_eq!(parse_month_year(input).unwrap(), (month, year));
+    }
+
+    #[cfg(feature = "all-languages")]
+    #[rstest]
+    #[case(" janvier, 2025 ", Month::January, 2025)]
+    #[case("2024 października", Month::October, 2024)]
+    fn test_parse_month_year_international(
+        #[case] input: &str,
+        #[case] month: Month,
+        #[case] year: i32,
+    ) {
+        assert_eq!(parse_month_year(input).unwrap(), (month, year));
+    }
+
+    #[rstest]
+    #[case("march")] // no year
+    #[case("march 24")] // two-digit year
+    #[case("march 20245")] // five digits
+    #[case("2024")] // no month
+    #[case("2024 xyz")] // invalid month
+    #[case("mar 2024 apr")] // month on both sides
+    fn test_parse_month_year_invalid(#[case] input: &str) {
+        assert!(matches!(
+            parse_month_year(input),
+            Err(ValidationError::InvalidEnumValue(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_months_matches_parse_month() {
+        let inputs = [
+            "january",
+            "JAN.",
+            "3rd",
+            "13",
+            "",
+            "enero",
+            "ARALIK",
+            "janurary",
+            "marsh",
+            "j@nuary",
+            "xxxaprilyyy",
+            "sebtembar",
+        ];
+        let expected: Vec<_> = inputs.iter().map(parse_month).collect();
+        assert_eq!(parse_months(inputs), expected);
+        assert!(parse_months([]).is_empty());
+    }
+
+    #[test]
+    fn test_parse_month_from_multiple_threads() {
+        let inputs = [
+            "january", "feb", "03", "4th", "enero", "ağustos", "ene", "dez.", "septmber", "xyz",
+        ];
+        let expected: Vec<_> = inputs.iter().map(parse_month).collect();
+
+        std::thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| {
+                    for _ in 0..100 {
+                        let results: Vec<_> = inputs.iter().map(parse_month).collect();
+                        assert_eq!(results, expected);
+                    }
+                });
+            }
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_variant_table_is_sorted_and_complete() {
+        let table = variant_table();
+        assert!(table.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        for (variant, month, _) in lang::variants().chain(lang::abbreviations()) {
+            assert_eq!(match_variant(variant), Some(month), "{variant}");
+        }
+    }
+
     // Test error messages
     #[test]
     fn test_error_messages() {
         let err = parse_month("invalid").unwrap_err();
         assert!(matches!(err, ValidationError::InvalidEnumValue(_)));
+        assert_eq!(
+            err.to_string(),
+            "Invalid month: invalid. Enter a month from January to December"
+        );
     }
 
     // Add some specific edge cases
@@ -444,12 +4106,389 @@ mod tests {
         assert_eq!(parse_month(input).unwrap(), expected);
     }
 
+    #[cfg(not(any(feature = "lang-es", feature = "lang-fr")))]
+    #[test]
+    fn test_without_international_variants() {
+        assert_eq!(parse_month("january"), Ok(Month::January));
+        assert_eq!(parse_month("03"), Ok(Month::March));
+        assert!(parse_month("enero").is_err());
+        assert!(parse_month_exact("janv").is_err());
+    }
+
+    #[cfg(all(feature = "lang-fr", not(feature = "lang-es")))]
+    #[test]
+    fn test_only_compiled_in_languages_are_matched() {
+        assert_eq!(parse_month("janvier"), Ok(Month::January));
+        assert_eq!(parse_month("janv."), Ok(Month::January));
+        assert!(parse_month("enero").is_err());
+        assert_eq!(Month::January.localized_name(Language::French), "janvier");
+        assert_eq!(Month::January.localized_name(Language::Spanish), "january");
+    }
+
     // Test internationalization cases if supported
+    #[cfg(feature = "all-languages")]
     #[rstest]
     #[case("enero", Month::January)] // Spanish
     #[case("janvier", Month::January)] // French
     #[case("januar", Month::January)] // German
+    #[case("Jänner", Month::January)] // German (Austria)
+    #[case("janner", Month::January)] // German (Austria), without the umlaut
+    #[case("Feber", Month::February)] // German (Austria)
+    #[case("augusti", Month::August)] // Swedish
+    #[case("desember", Month::December)] // Norwegian
+    #[case("maj", Month::May)] // Danish
+    #[case("正月", Month::January)] // Chinese (traditional)
+    #[case("腊月", Month::December)] // Chinese (traditional)
+    #[case("臘月", Month::December)] // Chinese (traditional script)
+    #[case("3月", Month::March)] // Japanese
+    #[case("12月", Month::December)] // Japanese
+    #[case("弥生", Month::March)] // Japanese (classical)
+    #[case("오월", Month::May)] // Korean
+    #[case("십이월", Month::December)] // Korean
+    #[case("3월", Month::March)] // Korean
+    #[case("ağustos", Month::August)] // Turkish
+    #[case("aralık", Month::December)] // Turkish
+    #[case("OCAK", Month::January)] // Turkish, uppercase
+    #[case("ARALIK", Month::December)] // Turkish, uppercase dotless i
+    #[case("NİSAN", Month::April)] // Turkish, uppercase dotted i
+    #[case("जनवरी", Month::January)] // Hindi
+    #[case("फ़रवरी", Month::February)] // Hindi
+    #[case("दिसंबर", Month::December)] // Hindi
+    #[case("दिसम्बर", Month::December)] // Hindi, half nasal consonant
+    #[case("maret", Month::March)] // Indonesian
+    #[case("desember", Month::December)] // Indonesian
+    #[case("ogos", Month::August)] // Malay
+    #[case("tháng 3", Month::March)] // Vietnamese
+    #[case("tháng 03", Month::March)] // Vietnamese, leading zero
+    #[case("THÁNG 12", Month::December)] // Vietnamese, uppercase
+    #[case("thang 7", Month::July)] // Vietnamese, without diacritics
+    #[case("tháng mười hai", Month::December)] // Vietnamese
+    #[case("Tháng Giêng", Month::January)] // Vietnamese (traditional)
+    #[case("maaliskuu", Month::March)] // Finnish
+    #[case("joulukuu", Month::December)] // Finnish
+    #[case("tammikuuta", Month::January)] // Finnish (partitive)
+    #[case("KESÄKUUSSA", Month::June)] // Finnish (inessive), uppercase
+    #[case("březen", Month::March)] // Czech
+    #[case("prosince", Month::December)] // Czech (genitive)
+    #[case("ŘÍJEN", Month::October)] // Czech, uppercase
+    #[case("december", Month::December)] // Slovak
+    #[case("júl", Month::July)] // Slovak
+    #[case("septembra", Month::September)] // Slovak (genitive)
+    #[case("augusztus", Month::August)] // Hungarian
+    #[case("május", Month::May)] // Hungarian
+    #[case("marcius", Month::March)] // Hungarian, without accents
+    #[case("brezen", Month::March)] // Czech, without accents
+    #[case("thang muoi hai", Month::December)] // Vietnamese, without accents
+    #[case("березень", Month::March)] // Ukrainian
+    #[case("грудня", Month::December)] // Ukrainian (genitive)
+    #[case("СІЧЕНЬ", Month::January)] // Ukrainian, uppercase
+    #[case("מרץ", Month::March)] // Hebrew
+    #[case("דצמבר", Month::December)] // Hebrew
+    #[case("מרצ", Month::March)] // Hebrew, regular instead of final letter
+    #[case("\u{200f}יוני", Month::June)] // Hebrew, with a right-to-left mark
+    #[case("января", Month::January)] // Russian (genitive)
+    #[case("stycznia", Month::January)] // Polish (genitive)
+    #[case("października", Month::October)] // Polish (genitive)
+    #[case("JANVIER", Month::January)] // uppercase international variant
+    #[case("FÉVRIER", Month::February)] // uppercase non-ASCII variant
     fn test_international_variants(#[case] input: &str, #[case] expected: Month) {
+        assert_eq!(parse_month_with_score(input), Ok((expected, 1.0)));
+        assert_eq!(parse_month_exact(input), Ok(expected));
+    }
+
+    #[cfg(feature = "lang-uk")]
+    #[test]
+    fn test_ukrainian_letters_are_distinct() {
+        assert_eq!(parse_month_exact("січень"), Ok(Month::January));
+        assert!(parse_month_exact("сїчень").is_err()); // ї instead of і
+        assert!(parse_month_exact("сiчень").is_err()); // Latin i instead of і
+    }
+
+    #[cfg(feature = "lang-ar")]
+    #[rstest]
+    #[case("يناير", Month::January)]
+    #[case("يَنَايِر", Month::January)] // tashkeel
+    #[case("يـنـايـر", Month::January)] // tatweel
+    #[case("\u{fef3}\u{fee8}\u{fe8e}\u{fef3}\u{feae}", Month::January)] // presentation forms
+    #[case("ابريل", Month::April)] // alef without hamza
+    #[case("أَبْرِيل", Month::April)]
+    fn test_arabic_normalization(#[case] input: &str, #[case] expected: Month) {
+        assert_eq!(parse_month_with_score(input), Ok((expected, 1.0)));
+    }
+
+    #[cfg(feature = "all-languages")]
+    #[rstest]
+    #[case("ene", Month::January)] // Spanish
+    #[case("janv", Month::January)] // French
+    #[case("févr", Month::February)] // French
+    #[case("jän", Month::January)] // German (Austria)
+    #[case("dez", Month::December)] // German
+    #[case("gen", Month::January)] // Italian
+    #[case("paź", Month::October)] // Polish
+    #[case("янв", Month::January)] // Russian
+    #[case("ŞUB", Month::February)] // Turkish
+    #[case("agt", Month::August)] // Indonesian
+    #[case("huhti", Month::April)] // Finnish
+    #[case("márc.", Month::March)] // Hungarian, period-terminated
+    #[case("квіт", Month::April)] // Ukrainian
+    #[case("Dez.", Month::December)] // German, period-terminated
+    #[case("janv.", Month::January)] // French, period-terminated
+    fn test_localized_abbreviations(#[case] input: &str, #[case] expected: Month) {
         assert_eq!(parse_month(input).unwrap(), expected);
+        assert_eq!(parse_month_exact(input), Ok(expected));
+    }
+
+    #[cfg(feature = "lang-fi")]
+    #[rstest]
+    #[case("tammi")]
+    #[case("helmi")]
+    #[case("kesä")]
+    #[case("heinä")]
+    #[case("elo")]
+    #[case("syys")]
+    #[case("loka")]
+    #[case("joulu")]
+    fn test_finnish_common_words_are_not_abbreviations(#[case] input: &str) {
+        assert!(parse_month_exact(input).is_err());
+        assert!(crate::extract_months(&format!("{input} on täällä")).is_empty());
+    }
+
+    #[rstest]
+    #[case(Month::January, "January", "Jan")]
+    #[case(Month::February, "February", "Feb")]
+    #[case(Month::March, "March", "Mar")]
+    #[case(Month::April, "April", "Apr")]
+    #[case(Month::May, "May", "May")]
+    #[case(Month::June, "June", "Jun")]
+    #[case(Month::July, "July", "Jul")]
+    #[case(Month::August, "August", "Aug")]
+    #[case(Month::September, "September", "Sep")]
+    #[case(Month::October, "October", "Oct")]
+    #[case(Month::November, "November", "Nov")]
+    #[case(Month::December, "December", "Dec")]
+    fn test_name_and_abbreviation(
+        #[case] month: Month,
+        #[case] name: &str,
+        #[case] abbreviation: &str,
+    ) {
+        assert_eq!(month.name(), name);
+        assert_eq!(month.abbreviation(), abbreviation);
+        assert_eq!(parse_month(month.name()), Ok(month));
+        assert_eq!(parse_month(month.abbreviation()), Ok(month));
+    }
+
+    #[test]
+    fn test_succ_and_pred() {
+        for (_, month) in MONTH_NAMES {
+            assert_eq!(month.succ().pred(), *month);
+            assert_eq!(month.succ().number(), month.number() % 12 + 1);
+        }
+        assert_eq!(Month::December.succ(), Month::January);
+        assert_eq!(Month::January.pred(), Month::December);
+    }
+
+    #[rstest]
+    #[case(Month::January, 1)]
+    #[case(Month::March, 1)]
+    #[case(Month::April, 2)]
+    #[case(Month::June, 2)]
+    #[case(Month::July, 3)]
+    #[case(Month::September, 3)]
+    #[case(Month::October, 4)]
+    #[case(Month::December, 4)]
+    fn test_quarter(#[case] month: Month, #[case] expected: u8) {
+        assert_eq!(month.quarter(), expected);
+    }
+
+    #[rstest]
+    #[case(Month::April, Month::April, 1, 1)]
+    #[case(Month::June, Month::April, 3, 1)]
+    #[case(Month::July, Month::April, 4, 2)]
+    #[case(Month::December, Month::April, 9, 3)]
+    #[case(Month::January, Month::April, 10, 4)]
+    #[case(Month::March, Month::April, 12, 4)]
+    #[case(Month::October, Month::October, 1, 1)]
+    #[case(Month::September, Month::October, 12, 4)]
+    fn test_fiscal_year(
+        #[case] month: Month,
+        #[case] fiscal_start: Month,
+        #[case] fiscal_month: u8,
+        #[case] fiscal_quarter: u8,
+    ) {
+        assert_eq!(month.fiscal_month(fiscal_start), fiscal_month);
+        assert_eq!(month.fiscal_quarter(fiscal_start), fiscal_quarter);
+    }
+
+    #[test]
+    fn test_fiscal_quarter_matches_calendar_quarter() {
+        for (_, month) in MONTH_NAMES {
+            assert_eq!(month.fiscal_quarter(Month::January), month.quarter());
+            assert_eq!(month.fiscal_month(Month::January), month.number());
+        }
+    }
+
+    #[rstest]
+    #[case(Month::February, 2000, 29)]
+    #[case(Month::February, 1900, 28)]
+    #[case(Month::February, 2023, 28)]
+    #[case(Month::February, 2024, 29)]
+    #[case(Month::February, -4, 29)] // proleptic: 5 BC
+    #[case(Month::April, 2024, 30)]
+    #[case(Month::January, 2023, 31)]
+    #[case(Month::December, 1900, 31)]
+    fn test_days_in_month(#[case] month: Month, #[case] year: i32, #[case] expected: u8) {
+        assert_eq!(month.days_in_month(year), expected);
+    }
+
+    #[test]
+    fn test_days() {
+        let total: u32 = MONTH_NAMES.iter().map(|(_, m)| u32::from(m.days())).sum();
+        assert_eq!(total, 365);
+        let leap: u32 = MONTH_NAMES
+            .iter()
+            .map(|(_, m)| u32::from(m.days_in_month(2024)))
+            .sum();
+        assert_eq!(leap, 366);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_roundtrip() {
+        for (_, month) in MONTH_NAMES {
+            let converted = chrono::Month::from(*month);
+            assert_eq!(converted.number_from_month(), u32::from(month.number()));
+            assert_eq!(converted.name(), month.name());
+            assert_eq!(Month::from(converted), *month);
+        }
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_time_roundtrip() {
+        for (_, month) in MONTH_NAMES {
+            let converted = time::Month::from(*month);
+            assert_eq!(u8::from(converted), month.number());
+            assert_eq!(Month::from(converted), *month);
+        }
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_rkyv_roundtrip() {
+        for month in Month::all() {
+            let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&month).unwrap();
+            // One byte per month, without any padding
+            assert_eq!(bytes.len(), 1);
+            let archived = rkyv::access::<ArchivedMonth, rkyv::rancor::Error>(&bytes).unwrap();
+            let deserialized: Month =
+                rkyv::deserialize::<Month, rkyv::rancor::Error>(archived).unwrap();
+            assert_eq!(deserialized, month);
+        }
+    }
+
+    /// Our `succ`/`pred` agree with `time::Month::next`/`previous`,
+    /// including the wrap-around at the end of the year
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_time_succ_pred() {
+        for (_, month) in MONTH_NAMES {
+            let converted = time::Month::from(*month);
+            assert_eq!(Month::from(converted.next()), month.succ());
+            assert_eq!(Month::from(converted.previous()), month.pred());
+        }
+        assert_eq!(
+            time::Month::from(Month::December.succ()),
+            time::Month::January
+        );
+        assert_eq!(
+            time::Month::from(Month::January.pred()),
+            time::Month::December
+        );
+    }
+
+    #[rstest]
+    #[case(Month::July, Hemisphere::Northern, Season::Summer)]
+    #[case(Month::July, Hemisphere::Southern, Season::Winter)]
+    #[case(Month::December, Hemisphere::Northern, Season::Winter)]
+    #[case(Month::December, Hemisphere::Southern, Season::Summer)]
+    #[case(Month::March, Hemisphere::Northern, Season::Spring)]
+    #[case(Month::March, Hemisphere::Southern, Season::Autumn)]
+    #[case(Month::November, Hemisphere::Northern, Season::Autumn)]
+    fn test_season(#[case] month: Month, #[case] hemisphere: Hemisphere, #[case] expected: Season) {
+        assert_eq!(month.season(hemisphere), expected);
+    }
+
+    #[cfg(feature = "all-languages")]
+    #[rstest]
+    #[case(Month::March, Language::French, "mars")]
+    #[case(Month::December, Language::German, "dezember")]
+    #[case(Month::January, Language::Russian, "январь")] // nominative, not genitive
+    #[case(Month::May, Language::English, "may")]
+    fn test_localized_name(#[case] month: Month, #[case] lang: Language, #[case] expected: &str) {
+        assert_eq!(month.localized_name(lang), expected);
+    }
+
+    #[cfg(feature = "all-languages")]
+    #[rstest]
+    #[case(Month::January, Language::Spanish, "Enero")]
+    #[case(Month::February, Language::French, "Février")]
+    #[case(Month::August, Language::Turkish, "Ağustos")]
+    #[case(Month::January, Language::Russian, "Январь")]
+    #[case(Month::March, Language::Chinese, "三月")]
+    #[case(Month::May, Language::English, "May")]
+    fn test_localized_title(#[case] month: Month, #[case] lang: Language, #[case] expected: &str) {
+        assert_eq!(month.localized_title(lang), expected);
+    }
+
+    #[test]
+    fn test_localized_title_english() {
+        for month in Month::all() {
+            assert_eq!(month.localized_title(Language::English), month.name());
+        }
+    }
+
+    #[test]
+    fn test_supported_languages() {
+        let languages = supported_languages();
+        assert!(languages.contains(&Language::English));
+        for (_, _, language) in lang::variants() {
+            assert!(languages.contains(&language), "{language:?}");
+        }
+        for (index, language) in languages.iter().enumerate() {
+            assert!(!languages[..index].contains(language), "{language:?}");
+        }
+    }
+
+    #[cfg(feature = "all-languages")]
+    #[test]
+    fn test_all_languages_are_supported() {
+        assert_eq!(supported_languages().len(), 24);
+    }
+
+    #[cfg(not(any(feature = "lang-es", feature = "lang-fr")))]
+    #[test]
+    fn test_supported_languages_without_features() {
+        assert!(!supported_languages().contains(&Language::Spanish));
+    }
+
+    #[test]
+    fn test_localized_name_roundtrip() {
+        let languages = lang::variants().map(|(_, _, language)| language);
+        for lang in languages.chain([Language::English]) {
+            for (_, month) in MONTH_NAMES {
+                assert_eq!(parse_month(month.localized_name(lang)).unwrap(), *month);
+            }
+        }
+    }
+
+    #[test]
+    fn test_localized_abbreviations_are_unambiguous() {
+        for (abbreviation, month, _) in lang::abbreviations() {
+            for (other, other_month, _) in lang::abbreviations().chain(lang::variants()) {
+                if abbreviation == other {
+                    assert_eq!(month, other_month, "{abbreviation} is ambiguous");
+                }
+            }
+        }
     }
 }
@@ -2,12 +2,35 @@
 //!
 //! Provides an interactive prompt for testing month parsing with
 //! colorized output and helpful messages.
+//!
+//! Months can also be passed as arguments (`fuzzymonth march feb 13`), in
+//! which case each one is parsed and the exit code reports whether all of
+//! them were valid.
+//!
+//! When stdin is not a terminal (`cat months.txt | fuzzymonth`), every line
+//! is parsed in batch mode, printing one result per line without the prompt
+//! and colors.
+//!
+//! With `--json`, results are printed as JSON objects instead: one per line
+//! at the prompt and in batch mode, or a single array for arguments.
+//!
+//! `--threshold <f64>` changes the similarity required for fuzzy matches,
+//! between 0.0 and 1.0 (default: 0.75).
+//!
+//! Months that had to be corrected by fuzzy matching are shown in yellow
+//! instead of green, and marked with `"exact":false` in JSON.
+//!
+//! Colors are only used when stdout is a terminal, and can be turned off with
+//! `--no-color` or by setting the `NO_COLOR` environment variable.
 
-use fuzzymonth::{parse_month, Month};
-use std::io::{self, Write};
+use fuzzymonth::{Month, MonthMatch, MonthParser, ValidationError};
+use std::ffi::OsStr;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::process::ExitCode;
 
 // ANSI color codes
 const GREEN: &str = "\x1b[32m";
+const YELLOW: &str = "\x1b[33m";
 const RED: &str = "\x1b[31m";
 const BLUE: &str = "\x1b[34m";
 const CYAN: &str = "\x1b[36m";
@@ -16,72 +39,270 @@ const RESET: &str = "\x1b[0m";
 mod display {
     use super::*;
 
-    pub(crate) fn print_welcome() {
+    pub(crate) fn print_welcome(color: bool) {
+        let (blue, reset) = if color { (BLUE, RESET) } else { ("", "") };
         println!(
-            "{BLUE}
+            "{blue}
 🗓 Fuzzy Month Parser
 Type a month name (any format) and press Enter
 Press Ctrl+C or Enter an empty line to exit
-{RESET}"
+{reset}"
         );
     }
 
     pub(crate) fn format_month(month: Month) -> String {
-        match month {
-            Month::January => "January (1)",
-            Month::February => "February (2)",
-            Month::March => "March (3)",
-            Month::April => "April (4)",
-            Month::May => "May (5)",
-            Month::June => "June (6)",
-            Month::July => "July (7)",
-            Month::August => "August (8)",
-            Month::September => "September (9)",
-            Month::October => "October (10)",
-            Month::November => "November (11)",
-            Month::December => "December (12)",
-        }
-        .to_string()
+        format!("{} ({})", month.name(), month.number())
     }
 
-    pub(crate) fn print_prompt() -> io::Result<()> {
-        print!("{CYAN}→ {RESET}");
+    pub(crate) fn print_prompt(color: bool) -> io::Result<()> {
+        if color {
+            print!("{CYAN}→ {RESET}");
+        } else {
+            print!("→ ");
+        }
         io::stdout().flush()
     }
 
-    pub(crate) fn print_error(input: &str) {
-        println!("{RED}✗ Invalid input: {input}{RESET}");
+    pub(crate) fn print_error(input: &str, color: bool) {
+        if color {
+            println!("{RED}✗ Invalid input: {input}{RESET}");
+        } else {
+            println!("✗ Invalid input: {input}");
+        }
+    }
+
+    /// Print a parsed month, in yellow rather than green if the input had to
+    /// be corrected by fuzzy matching
+    pub(crate) fn print_success(result: &MonthMatch, color: bool) {
+        let month = result.month;
+        if color {
+            let green_or_yellow = if result.exact { GREEN } else { YELLOW };
+            println!("{green_or_yellow}✓ {}{RESET}", format_month(month));
+        } else {
+            println!("✓ {}", format_month(month));
+        }
     }
 
-    pub(crate) fn print_success(month: Month) {
-        println!("{GREEN}✓ {}{RESET}", format_month(month));
+    pub(crate) fn print_result(
+        input: &str,
+        result: &Result<MonthMatch, ValidationError>,
+        color: bool,
+    ) {
+        match result {
+            Ok(result) => print_success(result, color),
+            Err(_) => print_error(input, color),
+        }
     }
 
     pub(crate) fn print_goodbye() {
         println!("👋 Goodbye!");
     }
+
+    /// A parse result as a JSON object, e.g.
+    /// `{"input":"sept","success":true,"month":"September","number":9,"confidence":1,"exact":true}`
+    pub(crate) fn format_json(input: &str, result: &Result<MonthMatch, ValidationError>) -> String {
+        let input = json_string(input);
+        match result {
+            Ok(result) => format!(
+                r#"{{"input":{input},"success":true,"month":"{}","number":{},"confidence":{},"exact":{}}}"#,
+                result.month.name(),
+                result.month.number(),
+                result.score,
+                result.exact
+            ),
+            Err(_) => format!(
+                r#"{{"input":{input},"success":false,"month":null,"number":null,"confidence":null,"exact":null}}"#
+            ),
+        }
+    }
+
+    /// Quote and escape a string for JSON
+    fn json_string(value: &str) -> String {
+        let mut quoted = String::from('"');
+        for c in value.chars() {
+            match c {
+                '"' => quoted.push_str("\\\""),
+                '\\' => quoted.push_str("\\\\"),
+                c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", u32::from(c))),
+                c => quoted.push(c),
+            }
+        }
+        quoted.push('"');
+        quoted
+    }
 }
 
-fn main() -> io::Result<()> {
-    display::print_welcome();
+/// Command-line options
+struct Options {
+    /// Print results as JSON instead of colorized text
+    json: bool,
+    /// Use ANSI colors for text output
+    color: bool,
+    /// Parser configured from the options
+    parser: MonthParser,
+    /// Months to parse; if empty, the interactive prompt is used
+    inputs: Vec<String>,
+}
+
+impl Options {
+    fn from_args(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self {
+            json: false,
+            color: false,
+            parser: MonthParser::new(),
+            inputs: Vec::new(),
+        };
+        let mut no_color_flag = false;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--json" => options.json = true,
+                "--no-color" => no_color_flag = true,
+                "--threshold" => {
+                    let value = args
+                        .next()
+                        .ok_or("--threshold requires a value between 0.0 and 1.0")?;
+                    let threshold = value
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|threshold| (0.0..=1.0).contains(threshold))
+                        .ok_or_else(|| {
+                            format!(
+                                "invalid threshold: {value} (expected a value between 0.0 and 1.0)"
+                            )
+                        })?;
+                    options.parser = options.parser.threshold(threshold);
+                }
+                _ => options.inputs.push(arg),
+            }
+        }
+        options.color = use_color(
+            io::stdout().is_terminal(),
+            std::env::var_os("NO_COLOR").as_deref(),
+            no_color_flag,
+        );
+        Ok(options)
+    }
+}
+
+/// Whether to use colors, given whether stdout is a terminal, the value of
+/// the `NO_COLOR` environment variable and whether `--no-color` was passed
+///
+/// Following <https://no-color.org>, an empty `NO_COLOR` doesn't count.
+fn use_color(is_tty: bool, no_color: Option<&OsStr>, no_color_flag: bool) -> bool {
+    is_tty && no_color.is_none_or(OsStr::is_empty) && !no_color_flag
+}
+
+/// Parse every argument and report whether all of them are valid months
+fn parse_args(options: &Options) -> ExitCode {
+    let results: Vec<_> = options
+        .inputs
+        .iter()
+        .map(|input| options.parser.parse_detailed(input))
+        .collect();
+
+    if options.json {
+        let objects: Vec<String> = options
+            .inputs
+            .iter()
+            .zip(&results)
+            .map(|(input, result)| display::format_json(input, result))
+            .collect();
+        println!("[{}]", objects.join(","));
+    } else {
+        for (input, result) in options.inputs.iter().zip(&results) {
+            display::print_result(input, result, options.color);
+        }
+    }
+
+    if results.iter().all(Result::is_ok) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Parse every line of a non-interactive stdin, printing one result per line
+///
+/// Empty lines are reported as invalid rather than ending the input, so that
+/// the output lines up with the input lines.
+fn parse_lines(options: &Options) -> io::Result<ExitCode> {
+    let mut all_valid = true;
+    for line in io::stdin().lock().lines() {
+        let line = line?;
+        let input = line.trim();
+        let result = options.parser.parse_detailed(input);
+        all_valid &= result.is_ok();
+        if options.json {
+            println!("{}", display::format_json(input, &result));
+        } else {
+            display::print_result(input, &result, false);
+        }
+    }
+
+    Ok(if all_valid {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+fn main() -> io::Result<ExitCode> {
+    let options = match Options::from_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("error: {message}");
+            return Ok(ExitCode::from(2));
+        }
+    };
+    if !options.inputs.is_empty() {
+        return Ok(parse_args(&options));
+    }
+    if !io::stdin().is_terminal() {
+        return parse_lines(&options);
+    }
+
+    // JSON output is meant for other programs, so it skips the decorations
+    if !options.json {
+        display::print_welcome(options.color);
+    }
 
     loop {
-        display::print_prompt()?;
+        if !options.json {
+            display::print_prompt(options.color)?;
+        }
 
         let mut input = String::new();
         io::stdin().read_line(&mut input)?;
 
         let input = input.trim();
         if input.is_empty() {
-            display::print_goodbye();
+            if !options.json {
+                display::print_goodbye();
+            }
             break;
         }
 
-        match parse_month(input) {
-            Ok(month) => display::print_success(month),
-            Err(_) => display::print_error(input),
+        let result = options.parser.parse_detailed(input);
+        if options.json {
+            println!("{}", display::format_json(input, &result));
+        } else {
+            display::print_result(input, &result, options.color);
         }
     }
 
-    Ok(())
+    Ok(ExitCode::SUCCESS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_use_color() {
+        assert!(use_color(true, None, false));
+        assert!(use_color(true, Some(OsStr::new("")), false));
+        assert!(!use_color(true, Some(OsStr::new("1")), false));
+        assert!(!use_color(true, None, true));
+        assert!(!use_color(false, None, false));
+    }
 }
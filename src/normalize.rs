@@ -0,0 +1,539 @@
+//! Normalization of user input and variant tables before matching.
+
+use alloc::{borrow::Cow, string::String};
+
+/// Trim surrounding whitespace and a single trailing period
+///
+/// Many locales write abbreviated months with a period (`"Jan."`, `"Dez."`),
+/// which would otherwise count as an extra character during matching.
+pub fn trim(value: &str) -> &str {
+    let value = value.trim();
+    value.strip_suffix('.').map_or(value, str::trim_end)
+}
+
+/// Case-fold a string in a locale-independent way suitable for matching
+///
+/// Lowercasing is meant for display, not for comparing strings: Greek words
+/// lowercase to a final `ς` at the end but `σ` elsewhere, and the German `ß`
+/// has no single-character uppercase counterpart in most text. Unicode case
+/// folding maps both sides to the same form instead (`σ` and `ss`), see
+/// [`case_fold`].
+///
+/// The default Unicode mappings are also wrong for Turkish: the dotted
+/// capital `İ` becomes `i̇` (an `i` followed by a combining dot) and the
+/// dotless `ı` stays distinct from `i`. Both are folded to a plain `i` so that
+/// `"ARALIK"`, `"aralık"` and `"aralik"` compare equal. Diacritics are removed
+/// as well (see [`fold_chars`]).
+///
+/// The same folding has to be applied to both sides of a comparison.
+#[cfg(any(feature = "std", test))]
+pub fn fold_case(value: &str) -> String {
+    let mut folded = String::new();
+    fold_case_into(value, &mut folded);
+    folded
+}
+
+/// Like [`fold_case`], but writes into an existing buffer to reuse its allocation
+pub fn fold_case_into(value: &str, buffer: &mut String) {
+    buffer.clear();
+    buffer.extend(fold_chars(value));
+}
+
+/// Whether two strings are equal after [`fold_case`], without allocating
+pub fn folded_eq(a: &str, b: &str) -> bool {
+    fold_chars(a).eq(fold_chars(b))
+}
+
+/// Cyrillic and Greek letters that look like Latin ones, with their Latin
+/// look-alikes
+const HOMOGLYPHS: &[(char, char)] = &[
+    // Cyrillic
+    ('а', 'a'),
+    ('в', 'b'),
+    ('е', 'e'),
+    ('і', 'i'),
+    ('ј', 'j'),
+    ('к', 'k'),
+    ('м', 'm'),
+    ('н', 'h'),
+    ('о', 'o'),
+    ('р', 'p'),
+    ('с', 'c'),
+    ('т', 't'),
+    ('у', 'y'),
+    ('х', 'x'),
+    ('ѕ', 's'),
+    ('А', 'A'),
+    ('В', 'B'),
+    ('Е', 'E'),
+    ('І', 'I'),
+    ('Ј', 'J'),
+    ('К', 'K'),
+    ('М', 'M'),
+    ('Н', 'H'),
+    ('О', 'O'),
+    ('Р', 'P'),
+    ('С', 'C'),
+    ('Т', 'T'),
+    ('У', 'Y'),
+    ('Х', 'X'),
+    ('Ѕ', 'S'),
+    // Greek
+    ('α', 'a'),
+    ('ε', 'e'),
+    ('ι', 'i'),
+    ('κ', 'k'),
+    ('ν', 'v'),
+    ('ο', 'o'),
+    ('ρ', 'p'),
+    ('υ', 'u'),
+    ('Α', 'A'),
+    ('Β', 'B'),
+    ('Ε', 'E'),
+    ('Ζ', 'Z'),
+    ('Η', 'H'),
+    ('Ι', 'I'),
+    ('Κ', 'K'),
+    ('Μ', 'M'),
+    ('Ν', 'N'),
+    ('Ο', 'O'),
+    ('Ρ', 'P'),
+    ('Τ', 'T'),
+    ('Υ', 'Y'),
+    ('Χ', 'X'),
+];
+
+/// Replace Cyrillic and Greek homoglyphs in otherwise Latin input
+///
+/// Copy-pasted text sometimes mixes scripts, like `"Januаry"` with a
+/// Cyrillic `а`, which looks right but matches nothing. Input without any
+/// ASCII letters is left alone, so that Russian or Greek month names keep
+/// their meaning.
+pub fn fold_homoglyphs(value: &str) -> Cow<'_, str> {
+    let homoglyph = |c: char| {
+        HOMOGLYPHS
+            .iter()
+            .find(|(homoglyph, _)| *homoglyph == c)
+            .map(|(_, latin)| *latin)
+    };
+    if !value.chars().any(|c| c.is_ascii_alphabetic())
+        || !value.chars().any(|c| homoglyph(c).is_some())
+    {
+        return Cow::Borrowed(value);
+    }
+    Cow::Owned(value.chars().map(|c| homoglyph(c).unwrap_or(c)).collect())
+}
+
+/// Characters commonly used in leetspeak, with the letters they stand for
+const LEETSPEAK: &[(char, char)] = &[
+    ('4', 'a'),
+    ('3', 'e'),
+    ('1', 'i'),
+    ('0', 'o'),
+    ('5', 's'),
+    ('@', 'a'),
+    ('$', 's'),
+];
+
+/// Replace leetspeak characters with the letters they stand for
+///
+/// `"m4rch"` becomes `"march"`. Input without any letters is left alone, so
+/// that plain numbers like `"3"` keep their numeric meaning. The buffer is
+/// decoded in a single pass, so this takes linear time even for long input.
+pub fn decode_leetspeak(buffer: &mut String) {
+    let is_leet = |c: char| LEETSPEAK.iter().any(|(leet, _)| *leet == c);
+    if !buffer.chars().any(char::is_alphabetic) || !buffer.chars().any(is_leet) {
+        return;
+    }
+    *buffer = buffer
+        .chars()
+        .map(|c| {
+            LEETSPEAK
+                .iter()
+                .find(|(leet, _)| *leet == c)
+                .map_or(c, |(_, letter)| *letter)
+        })
+        .collect();
+}
+
+/// Whether [`fold_for_matching`] leaves a string unchanged
+///
+/// Lowercase ASCII without leetspeak characters is already folded; anything
+/// else is conservatively assumed to need folding.
+fn is_folded(value: &str) -> bool {
+    value.chars().all(|c| {
+        c.is_ascii() && !c.is_ascii_uppercase() && !LEETSPEAK.iter().any(|(leet, _)| *leet == c)
+    })
+}
+
+/// [`fold_case`] and [`decode_leetspeak`], as done before fuzzy matching
+///
+/// Machine-generated input is often lowercase ASCII already, which is
+/// borrowed instead of copied.
+pub fn fold_for_matching(value: &str) -> Cow<'_, str> {
+    if is_folded(value) {
+        return Cow::Borrowed(value);
+    }
+    let mut folded = String::new();
+    fold_case_into(value, &mut folded);
+    decode_leetspeak(&mut folded);
+    Cow::Owned(folded)
+}
+
+/// Like [`fold_for_matching`], but writes into `buffer` when the value has
+/// to be folded, to reuse its allocation
+pub fn fold_for_matching_into<'a>(value: &'a str, buffer: &'a mut String) -> &'a str {
+    if is_folded(value) {
+        return value;
+    }
+    fold_case_into(value, buffer);
+    decode_leetspeak(buffer);
+    buffer
+}
+
+/// The characters of [`fold_case`], computed lazily
+///
+/// Besides lowercasing, diacritics are removed from Latin letters, both from
+/// precomposed letters (`á`) and as combining marks following an ASCII
+/// letter (`a` + `U+0301`), so that `"március"` and `"marcius"` compare
+/// equal. Other scripts keep their diacritics: the Cyrillic `й` and `ї` are
+/// distinct letters, not an `и` and an `і` with an accent.
+///
+/// Arabic text is normalized so that the same word compares equal regardless
+/// of how it was typed: presentation forms are replaced by their canonical
+/// letters (see [`unshape`]), diacritics (tashkeel) and the tatweel used to
+/// stretch words are removed, and the variants of alef with hamza or madda
+/// are folded to a plain alef. Hebrew vowel points are removed and final
+/// letter forms are folded to the regular letters (`ץ` to `צ`), and the
+/// invisible direction marks of right-to-left text are dropped.
+pub fn fold_chars(value: &str) -> impl Iterator<Item = char> + '_ {
+    let mut previous = None;
+    value
+        .chars()
+        .flat_map(unshape)
+        .flat_map(case_fold)
+        .filter_map(move |c| {
+            let folded = match c {
+                'ı' => 'i',
+                // Combining marks, including the dot above left behind by
+                // lowercasing the Turkish `İ`
+                '\u{300}'..='\u{36f}'
+                    if previous.is_some_and(|p: char| p.is_ascii_alphabetic()) =>
+                {
+                    return None
+                }
+                c if is_ignorable(c) => return None,
+                'أ' | 'إ' | 'آ' | 'ٱ' => 'ا',
+                'ך' => 'כ',
+                'ם' => 'מ',
+                'ן' => 'נ',
+                'ף' => 'פ',
+                'ץ' => 'צ',
+                _ => strip_diacritics(c),
+            };
+            previous = Some(folded);
+            Some(folded)
+        })
+}
+
+/// Unicode case folding of a single character
+///
+/// This is [`char::to_lowercase`] plus the (full) case folding mappings that
+/// differ from it: the final sigma `ς` folds to `σ`, and `ß`, `ẞ` and the
+/// long `ſ` fold to `ss` and `s`.
+fn case_fold(c: char) -> impl Iterator<Item = char> {
+    let special = match c {
+        'ς' => Some("σ"),
+        'ß' | 'ẞ' => Some("ss"),
+        'ſ' => Some("s"),
+        _ => None,
+    };
+    let lowercase = special.is_none().then(|| c.to_lowercase());
+    lowercase
+        .into_iter()
+        .flatten()
+        .chain(special.into_iter().flat_map(str::chars))
+}
+
+/// Whether a character is dropped entirely during folding
+const fn is_ignorable(c: char) -> bool {
+    matches!(
+        c,
+        // Arabic tashkeel, superscript alef and tatweel
+        '\u{64b}'..='\u{65f}' | '\u{670}' | '\u{640}'
+        // Hebrew points and cantillation marks
+        | '\u{591}'..='\u{5bd}' | '\u{5bf}' | '\u{5c1}' | '\u{5c2}' | '\u{5c4}' | '\u{5c5}' | '\u{5c7}'
+        // Direction marks, embeddings and isolates of right-to-left text
+        | '\u{61c}' | '\u{200e}' | '\u{200f}' | '\u{202a}'..='\u{202e}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+/// ASCII base letters of the letters with diacritics from `U+00C0` to `U+024F`
+/// (Latin-1 Supplement, Latin Extended-A and Latin Extended-B), indexed by
+/// code point, with `_` for characters that are not a letter with diacritics
+const LATIN_BASE_LETTERS: &[u8] = b"\
+    AAAAAA_CEEEEIIII_NOOOOO_OUUUUY__aaaaaa_ceeeeiiii_nooooo_ouuuuy_y\
+    AaAaAaCcCcCcCcDdDdEeEeEeEeEeGgGgGgGgHhHhIiIiIiIiI___JjKk_LlLlLl_\
+    _LlNnNnNn___OoOoOo__RrRrRrSsSsSsSsTtTtTtUuUuUuUuUuUuWwYyYZzZzZz_\
+    b_______________________________Oo_____________Uu_______________\
+    _____________AaIiOoUuUuUuUuUu_AaAa____GgKkOoOo__j___Gg__NnAa____\
+    AaAaEeEeIiIiOoOoRrRrUuUuSsTt__Hh______AaEeOoOoOoOoYy____________\
+    ________________\
+";
+
+/// ASCII base letters of the Latin Extended Additional block from `U+1E00` to
+/// `U+1EFF` (mostly Vietnamese), in the same format as [`LATIN_BASE_LETTERS`]
+const LATIN_EXTENDED_ADDITIONAL_BASE_LETTERS: &[u8] = b"\
+    AaBbBbBbCcDdDdDdDdDdEeEeEeEeEeFfGgHhHhHhHhHhIiIiKkKkKkLlLlLlLlMm\
+    MmMmNnNnNnNnOoOoOoOoPpPpRrRrRrRrSsSsSsSsSsTtTtTtTtUuUuUuUuUuVvVv\
+    WwWwWwWwWwXxXxYyZzZzZzhtwy______AaAaAaAaAaAaAaAaAaAaAaAaEeEeEeEe\
+    EeEeEeEeIiIiOoOoOoOoOoOoOoOoOoOoOoOoUuUuUuUuUuUuUuYyYyYyYy______\
+";
+
+/// The ASCII base letter of a precomposed Latin letter with diacritics, such
+/// as `a` for `á` or `e` for `ệ`; every other character is returned unchanged
+fn strip_diacritics(c: char) -> char {
+    let code = u32::from(c);
+    let (table, start) = match code {
+        0xc0..=0x24f => (LATIN_BASE_LETTERS, 0xc0),
+        0x1e00..=0x1eff => (LATIN_EXTENDED_ADDITIONAL_BASE_LETTERS, 0x1e00),
+        _ => return c,
+    };
+    usize::try_from(code - start)
+        .ok()
+        .and_then(|index| table.get(index))
+        .filter(|base| **base != b'_')
+        .map_or(c, |base| char::from(*base))
+}
+
+/// Canonical letters of the Arabic Presentation Forms-B block, in code point
+/// order starting at `U+FE80`, with the number of forms (isolated, final,
+/// initial, medial) each one has
+const ARABIC_PRESENTATION_FORMS: &[(&str, u32)] = &[
+    ("ء", 1),
+    ("آ", 2),
+    ("أ", 2),
+    ("ؤ", 2),
+    ("إ", 2),
+    ("ئ", 4),
+    ("ا", 2),
+    ("ب", 4),
+    ("ة", 2),
+    ("ت", 4),
+    ("ث", 4),
+    ("ج", 4),
+    ("ح", 4),
+    ("خ", 4),
+    ("د", 2),
+    ("ذ", 2),
+    ("ر", 2),
+    ("ز", 2),
+    ("س", 4),
+    ("ش", 4),
+    ("ص", 4),
+    ("ض", 4),
+    ("ط", 4),
+    ("ظ", 4),
+    ("ع", 4),
+    ("غ", 4),
+    ("ف", 4),
+    ("ق", 4),
+    ("ك", 4),
+    ("ل", 4),
+    ("م", 4),
+    ("ن", 4),
+    ("ه", 4),
+    ("و", 2),
+    ("ى", 2),
+    ("ي", 4),
+    ("لآ", 2),
+    ("لأ", 2),
+    ("لإ", 2),
+    ("لا", 2),
+];
+
+/// Replace an Arabic presentation form with the letter (or, for the lam-alef
+/// ligatures, the two letters) it is a shape of
+///
+/// Presentation forms are separate code points for the contextual shapes of
+/// each letter, which some legacy systems and PDF extractors emit instead of
+/// the canonical letters. Isolated forms of tashkeel (`U+FE70` to `U+FE7F`)
+/// are dropped. Every other character is returned unchanged.
+fn unshape(c: char) -> impl Iterator<Item = char> {
+    let base = match u32::from(c) {
+        0xfe70..=0xfe7f => Some(""),
+        code @ 0xfe80..=0xfefc => {
+            let mut offset = code - 0xfe80;
+            ARABIC_PRESENTATION_FORMS
+                .iter()
+                .find(|(_, forms)| {
+                    let found = offset < *forms;
+                    offset = offset.saturating_sub(*forms);
+                    found
+                })
+                .map(|(letters, _)| *letters)
+        }
+        _ => None,
+    };
+    let unchanged = base.is_none().then_some(c);
+    unchanged
+        .into_iter()
+        .chain(base.into_iter().flat_map(str::chars))
+}
+
+/// Whether a character is a combining mark (Unicode general category M) of
+/// one of the scripts with month names in this crate
+///
+/// Marks are part of the letter before them, but not alphabetic, such as the
+/// Devanagari virama in `"मार्च"` or an accent in decomposed (NFD) Latin text.
+/// Without a full Unicode table, only the blocks of the supported scripts are
+/// covered.
+pub fn is_mark(c: char) -> bool {
+    matches!(
+        u32::from(c),
+        // Combining diacritics, also used by decomposed Latin and Greek text
+        0x0300..=0x036f
+            | 0x1ab0..=0x1aff
+            | 0x1dc0..=0x1dff
+            | 0x20d0..=0x20ff
+            | 0xfe20..=0xfe2f
+            // Cyrillic
+            | 0x0483..=0x0489
+            // Hebrew points
+            | 0x0591..=0x05bd
+            | 0x05bf
+            | 0x05c1..=0x05c2
+            | 0x05c4..=0x05c5
+            | 0x05c7
+            // Arabic tashkeel
+            | 0x0610..=0x061a
+            | 0x064b..=0x065f
+            | 0x0670
+            | 0x06d6..=0x06dc
+            | 0x06df..=0x06e4
+            | 0x06e7..=0x06e8
+            | 0x06ea..=0x06ed
+            // Devanagari vowel signs, virama and nukta
+            | 0x0900..=0x0903
+            | 0x093a..=0x093c
+            | 0x093e..=0x094f
+            | 0x0951..=0x0957
+            | 0x0962..=0x0963
+            // Japanese (combining dakuten and handakuten)
+            | 0x3099..=0x309a
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("jan", "jan")]
+    #[case(" Jan. ", "Jan")]
+    #[case("sept .", "sept")]
+    #[case("dec..", "dec.")] // only a single period is removed
+    #[case(".", "")]
+    fn test_trim(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(trim(input), expected);
+    }
+
+    #[rstest]
+    #[case("JANUARY", "january")]
+    #[case("OCAK", "ocak")]
+    #[case("ΜΆΡΤΙΟΣ", "μάρτιοσ")] // Greek capital sigma
+    #[case("μάρτιος", "μάρτιοσ")] // Greek final sigma
+    #[case("STRASSE", "strasse")]
+    #[case("Straße", "strasse")]
+    #[case("ARALIK", "aralik")]
+    #[case("aralık", "aralik")]
+    #[case("NİSAN", "nisan")]
+    #[case("Şubat", "subat")]
+    #[case("Március", "marcius")]
+    #[case("ma\u{301}rcius", "marcius")] // combining accent
+    #[case("Tháng Mười Hai", "thang muoi hai")]
+    #[case("Łódź", "lodz")]
+    #[case("ЙЇЄ", "йїє")] // Cyrillic letters are kept
+    #[case("і\u{308}", "і\u{308}")] // even with a combining diaeresis
+    #[case("يَنَايِر", "يناير")] // tashkeel
+    #[case("يـنـايـر", "يناير")] // tatweel
+    #[case("\u{fef3}\u{fee8}\u{fe8e}\u{fef3}\u{feae}", "يناير")] // presentation forms
+    #[case("\u{fe83}\u{fe91}\u{feae}\u{fef4}\u{fede}", "ابريل")] // alef with hamza
+    #[case("\u{fefb}", "لا")] // lam-alef ligature
+    #[case("מרץ", "מרצ")] // Hebrew final letter
+    #[case("\u{200f}מֶרְץ\u{200e}", "מרצ")] // Hebrew points and direction marks
+    fn test_fold_case(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(fold_case(input), expected);
+        assert!(folded_eq(input, expected));
+    }
+
+    #[test]
+    fn test_fold_case_into_reuses_buffer() {
+        let mut buffer = String::from("leftover from a longer previous input");
+        fold_case_into("MÄRZ", &mut buffer);
+        assert_eq!(buffer, "marz");
+    }
+
+    #[rstest]
+    #[case("m4rch", "march")]
+    #[case("dec3mber", "december")]
+    #[case("j@nu@ry", "january")]
+    #[case("0ct0ber", "october")]
+    #[case("$3pt", "sept")]
+    #[case("ju1y", "juiy")]
+    #[case("3", "3")] // no letters, so it stays a number
+    #[case("12", "12")]
+    #[case("", "")]
+    fn test_decode_leetspeak(#[case] input: &str, #[case] expected: &str) {
+        let mut buffer = String::from(input);
+        decode_leetspeak(&mut buffer);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn test_decode_leetspeak_long_input() {
+        let mut buffer = format!("a{}", "0".repeat(100_000));
+        decode_leetspeak(&mut buffer);
+        assert_eq!(buffer, format!("a{}", "o".repeat(100_000)));
+    }
+
+    #[rstest]
+    #[case("march", "march", true)]
+    #[case("sept 2", "sept 2", true)]
+    #[case("  spaces stay ", "  spaces stay ", true)]
+    #[case("March", "march", false)]
+    #[case("m4rch", "march", false)]
+    #[case("février", "fevrier", false)]
+    #[case("3", "3", false)] // a leetspeak character, even though nothing changes
+    fn test_fold_for_matching(#[case] input: &str, #[case] expected: &str, #[case] borrowed: bool) {
+        let folded = fold_for_matching(input);
+        assert_eq!(folded, expected);
+        assert_eq!(matches!(folded, Cow::Borrowed(_)), borrowed);
+
+        let mut buffer = String::new();
+        assert_eq!(fold_for_matching_into(input, &mut buffer), expected);
+        assert_eq!(buffer.is_empty(), borrowed);
+    }
+
+    #[rstest]
+    #[case("Janu\u{430}ry", "January")] // Cyrillic а
+    #[case("\u{41c}arch", "March")] // Cyrillic М
+    #[case("\u{39c}\u{391}y", "MAy")] // Greek Μ and Α
+    #[case("march", "march")]
+    #[case("март", "март")] // no Latin letters
+    #[case("μάρτιος", "μάρτιος")]
+    fn test_fold_homoglyphs(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(fold_homoglyphs(input), expected);
+    }
+
+    #[test]
+    fn test_folded_eq() {
+        assert!(folded_eq("JaNuArY", "january"));
+        assert!(folded_eq("ŞUBAT", "şubat"));
+        assert!(folded_eq("ΜΆΡΤΙΟΣ", "μάρτιος"));
+        assert!(!folded_eq("janvier", "januar"));
+        assert!(!folded_eq("jan", "janv"));
+    }
+}
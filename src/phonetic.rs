@@ -0,0 +1,84 @@
+//! A simplified Metaphone-style phonetic encoding.
+//!
+//! This is only meant to compare misspellings against the twelve English
+//! month names, so it covers the most common sound-alike spellings and
+//! nothing more: vowels (apart from a leading one), `h`, `w` and `y` are
+//! dropped, letters that sound alike are mapped to a single code and repeated
+//! codes are collapsed.
+
+use alloc::string::String;
+
+/// Phonetic code of an English word
+///
+/// Returns `None` if the word contains anything other than ASCII letters.
+pub fn encode(word: &str) -> Option<String> {
+    if word.is_empty() || !word.bytes().all(|b| b.is_ascii_alphabetic()) {
+        return None;
+    }
+    let word = word.as_bytes();
+    let mut code = String::with_capacity(word.len());
+    let mut i = 0;
+    while i < word.len() {
+        let letter = word[i].to_ascii_lowercase();
+        let next = word.get(i + 1).map(u8::to_ascii_lowercase);
+        let soft = matches!(next, Some(b'e' | b'i' | b'y'));
+        let encoded = match letter {
+            b'a' | b'e' | b'i' | b'o' | b'u' if i == 0 => Some('A'),
+            b'a' | b'e' | b'i' | b'o' | b'u' | b'h' | b'w' | b'y' => None,
+            b'p' if next == Some(b'h') => Some('F'),
+            b'c' | b's' if next == Some(b'h') => Some('X'),
+            b'c' if soft => Some('S'),
+            b'g' if soft => Some('J'),
+            b'c' | b'g' | b'k' | b'q' => Some('K'),
+            b'd' | b't' => Some('T'),
+            b'v' | b'f' => Some('F'),
+            b'z' | b's' => Some('S'),
+            b'x' => {
+                push_code(&mut code, 'K');
+                Some('S')
+            }
+            other => Some(char::from(other.to_ascii_uppercase())),
+        };
+        if let Some(c) = encoded {
+            push_code(&mut code, c);
+        }
+        i += 1;
+    }
+    Some(code)
+}
+
+/// Append `c` to `code` unless it repeats the previous code
+fn push_code(code: &mut String, c: char) {
+    if !code.ends_with(c) {
+        code.push(c);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("january", "JNR")]
+    #[case("jenyooary", "JNR")]
+    #[case("february", "FBR")]
+    #[case("febyuwerry", "FBR")]
+    #[case("march", "MRX")]
+    #[case("august", "AKST")]
+    #[case("September", "SPTMBR")]
+    #[case("december", "TSMBR")]
+    #[case("phone", "FN")]
+    #[case("box", "BKS")]
+    fn test_encode(#[case] word: &str, #[case] expected: &str) {
+        assert_eq!(encode(word).as_deref(), Some(expected));
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("jan 2")]
+    #[case("août")]
+    fn test_encode_rejects_non_letters(#[case] word: &str) {
+        assert_eq!(encode(word), None);
+    }
+}
@@ -0,0 +1,256 @@
+//! Ranges of consecutive months such as `"January to March"`.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::iter::FusedIterator;
+
+use crate::{parse_month, Month, ValidationError};
+
+/// Characters separating the two ends of a range, e.g. `"jan-mar"` or `"nov–feb"`
+const SYMBOL_SEPARATORS: &[char] = &['-', '–', '—'];
+
+/// Words separating the two ends of a range, e.g. `"january to march"`
+const WORD_SEPARATORS: &[&str] = &["to", "through", "thru", "until", "till"];
+
+/// An inclusive range of months
+///
+/// A range whose `end` comes before its `start` wraps around the end of the
+/// year, so `November` to `February` covers four months.
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::{Month, MonthRange};
+///
+/// let range = MonthRange::parse("nov–feb").unwrap();
+/// let months: Vec<Month> = range.into_iter().collect();
+/// assert_eq!(
+///     months,
+///     [Month::November, Month::December, Month::January, Month::February]
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct MonthRange {
+    pub start: Month,
+    pub end: Month,
+}
+
+impl MonthRange {
+    /// Create a range from `start` to `end` (inclusive)
+    #[must_use]
+    pub const fn new(start: Month, end: Month) -> Self {
+        Self { start, end }
+    }
+
+    /// Parse a range of months from a string
+    ///
+    /// The two ends are separated by `-`, `–` (en dash), `—` (em dash) or one
+    /// of the words `to`, `through`, `thru`, `until` and `till`. Each end is
+    /// parsed with [`parse_month`], so abbreviations, typos and international
+    /// names are accepted.
+    ///
+    /// ```
+    /// use fuzzymonth::{Month, MonthRange};
+    ///
+    /// let range = MonthRange::parse("January to March").unwrap();
+    /// assert_eq!(range, MonthRange::new(Month::January, Month::March));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` variant if no separator is found or if either end is
+    /// not a valid month.
+    pub fn parse(value: &str) -> Result<Self, ValidationError> {
+        let (start, end) = split(value).ok_or_else(|| {
+            ValidationError::InvalidEnumValue(format!(
+                "Invalid month range: {value}. Enter two months separated by \"-\" or \"to\""
+            ))
+        })?;
+        Ok(Self::new(parse_month(&start)?, parse_month(&end)?))
+    }
+}
+
+/// Parse a range like [`MonthRange::parse`], but also accept a single month
+/// as a range of just that month
+///
+/// ```
+/// use fuzzymonth::{Month, MonthRange};
+///
+/// let range: MonthRange = "jan-mar".parse().unwrap();
+/// assert_eq!(range, MonthRange::new(Month::January, Month::March));
+///
+/// let range: MonthRange = "march".parse().unwrap();
+/// assert_eq!(range, MonthRange::new(Month::March, Month::March));
+/// ```
+impl core::str::FromStr for MonthRange {
+    type Err = ValidationError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if split(value).is_none() {
+            let month = parse_month(value)?;
+            return Ok(Self::new(month, month));
+        }
+        Self::parse(value)
+    }
+}
+
+/// Split a range into its two ends, trying symbol separators before words
+fn split(value: &str) -> Option<(String, String)> {
+    if let Some((start, end)) = value.split_once(SYMBOL_SEPARATORS) {
+        return Some((start.to_string(), end.to_string()));
+    }
+
+    let words: Vec<&str> = value.split_whitespace().collect();
+    let position = words.iter().position(|word| {
+        WORD_SEPARATORS
+            .iter()
+            .any(|separator| word.eq_ignore_ascii_case(separator))
+    })?;
+    Some((words[..position].join(" "), words[position + 1..].join(" ")))
+}
+
+impl IntoIterator for MonthRange {
+    type Item = Month;
+    type IntoIter = MonthRangeIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let remaining = (self.end.number() + 12 - self.start.number()) % 12 + 1;
+        MonthRangeIter {
+            next: self.start,
+            remaining: usize::from(remaining),
+        }
+    }
+}
+
+/// Iterator over the months of a [`MonthRange`] in order
+#[derive(Debug, Clone)]
+pub struct MonthRangeIter {
+    next: Month,
+    remaining: usize,
+}
+
+impl Iterator for MonthRangeIter {
+    type Item = Month;
+
+    fn next(&mut self) -> Option<Month> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let month = self.next;
+        self.next = month.succ();
+        self.remaining -= 1;
+        Some(month)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for MonthRangeIter {}
+
+impl FusedIterator for MonthRangeIter {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("Jan-Mar", Month::January, Month::March)]
+    #[case("jan - mar", Month::January, Month::March)]
+    #[case("January to March", Month::January, Month::March)]
+    #[case("april through june", Month::April, Month::June)]
+    #[case("May UNTIL July", Month::May, Month::July)]
+    #[case("nov–feb", Month::November, Month::February)] // en dash
+    #[case("October — December", Month::October, Month::December)] // em dash
+    #[case("sept. to oct.", Month::September, Month::October)]
+    fn test_parse(#[case] input: &str, #[case] start: Month, #[case] end: Month) {
+        assert_eq!(
+            MonthRange::parse(input).unwrap(),
+            MonthRange::new(start, end)
+        );
+    }
+
+    #[cfg(all(feature = "lang-es", feature = "lang-fr"))]
+    #[rstest]
+    #[case("octobre — décembre", Month::October, Month::December)] // French
+    #[case("enero-marzo", Month::January, Month::March)] // Spanish
+    fn test_parse_international(#[case] input: &str, #[case] start: Month, #[case] end: Month) {
+        assert_eq!(
+            MonthRange::parse(input).unwrap(),
+            MonthRange::new(start, end)
+        );
+    }
+
+    #[rstest]
+    #[case("january")] // no separator
+    #[case("january march")] // no separator
+    #[case("jan-")] // missing end
+    #[case("to march")] // missing start
+    #[case("jan-xyz")] // invalid end
+    fn test_parse_invalid(#[case] input: &str) {
+        assert!(matches!(
+            MonthRange::parse(input),
+            Err(ValidationError::InvalidEnumValue(_))
+        ));
+    }
+
+    #[rstest]
+    #[case("jan-mar", Month::January, Month::March)]
+    #[case("April through June", Month::April, Month::June)]
+    #[case("march", Month::March, Month::March)]
+    #[case(" Sept. ", Month::September, Month::September)]
+    fn test_from_str(#[case] input: &str, #[case] start: Month, #[case] end: Month) {
+        assert_eq!(input.parse(), Ok(MonthRange::new(start, end)));
+    }
+
+    #[rstest]
+    #[case("xyz")]
+    #[case("january march")]
+    #[case("jan-")]
+    #[case("jan-xyz")]
+    fn test_from_str_invalid(#[case] input: &str) {
+        assert!(matches!(
+            input.parse::<MonthRange>(),
+            Err(ValidationError::InvalidEnumValue(_))
+        ));
+    }
+
+    #[test]
+    fn test_iter() {
+        let range = MonthRange::new(Month::January, Month::March);
+        let months: Vec<Month> = range.into_iter().collect();
+        assert_eq!(months, [Month::January, Month::February, Month::March]);
+    }
+
+    #[test]
+    fn test_iter_wraps_around() {
+        let range = MonthRange::parse("nov–feb").unwrap();
+        let months: Vec<Month> = range.into_iter().collect();
+        assert_eq!(
+            months,
+            [
+                Month::November,
+                Month::December,
+                Month::January,
+                Month::February
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(Month::March, Month::March, 1)]
+    #[case(Month::January, Month::December, 12)]
+    #[case(Month::April, Month::March, 12)]
+    #[case(Month::November, Month::February, 4)]
+    fn test_len(#[case] start: Month, #[case] end: Month, #[case] expected: usize) {
+        let iter = MonthRange::new(start, end).into_iter();
+        assert_eq!(iter.len(), expected);
+        assert_eq!(iter.count(), expected);
+    }
+}
@@ -0,0 +1,360 @@
+//! Compact sets of months.
+
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+use crate::{month_from_number, parse_month, Month, ValidationError};
+
+/// A set of months, stored as a bitmask
+///
+/// Iteration always yields the months in calendar order, no matter in which
+/// order they were inserted.
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::{Month, MonthSet};
+///
+/// let mut open = MonthSet::new();
+/// open.insert(Month::December);
+/// open.insert(Month::June);
+/// assert!(open.contains(Month::June));
+/// assert!(!open.contains(Month::July));
+///
+/// let months: Vec<Month> = open.iter().collect();
+/// assert_eq!(months, [Month::June, Month::December]);
+/// ```
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Default)]
+pub struct MonthSet {
+    bits: u16,
+}
+
+impl MonthSet {
+    /// Create an empty set
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { bits: 0 }
+    }
+
+    /// Create a set of all twelve months
+    #[must_use]
+    pub const fn all() -> Self {
+        Self { bits: 0x0fff }
+    }
+
+    /// The bit of a month in the mask
+    const fn bit(month: Month) -> u16 {
+        1 << (month.number() - 1)
+    }
+
+    /// Add a month, returning whether it was newly inserted
+    pub const fn insert(&mut self, month: Month) -> bool {
+        let inserted = !self.contains(month);
+        self.bits |= Self::bit(month);
+        inserted
+    }
+
+    /// Remove a month, returning whether it was in the set
+    pub const fn remove(&mut self, month: Month) -> bool {
+        let removed = self.contains(month);
+        self.bits &= !Self::bit(month);
+        removed
+    }
+
+    /// Whether the set contains a month
+    #[must_use]
+    pub const fn contains(self, month: Month) -> bool {
+        self.bits & Self::bit(month) != 0
+    }
+
+    /// Number of months in the set
+    #[must_use]
+    pub const fn len(self) -> usize {
+        // At most 12
+        #[allow(clippy::as_conversions)]
+        let len = self.bits.count_ones() as usize;
+        len
+    }
+
+    /// Whether the set is empty
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+
+    /// Months that are in either set
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self {
+            bits: self.bits | other.bits,
+        }
+    }
+
+    /// Months that are in both sets
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self {
+            bits: self.bits & other.bits,
+        }
+    }
+
+    /// Iterate over the months in calendar order
+    #[must_use]
+    pub const fn iter(self) -> MonthSetIter {
+        MonthSetIter { bits: self.bits }
+    }
+
+    /// Parse a list of months such as `"January, March and December"`
+    ///
+    /// The list is split on commas, whitespace and the word `and`, and each
+    /// part is parsed with [`parse_month`]. Where a word between commas and
+    /// `and` is not a month on its own, the words are parsed together instead,
+    /// so that names of several words like `"the third month"` are found.
+    /// Repeated months are only counted once. This is strict: if any part is
+    /// not a month, the whole list is rejected. Use [`MonthSet::parse_lenient`] to skip invalid parts instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fuzzymonth::{Month, MonthSet};
+    ///
+    /// let set = MonthSet::parse("January, March and December").unwrap();
+    /// let months: Vec<Month> = set.iter().collect();
+    /// assert_eq!(months, [Month::January, Month::March, Month::December]);
+    ///
+    /// assert!(MonthSet::parse("jan, xyz").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` variant for the first part that is not a valid month.
+    pub fn parse(value: &str) -> Result<Self, ValidationError> {
+        months(value).into_iter().collect()
+    }
+
+    /// Parse a list of months like [`MonthSet::parse`], skipping invalid parts
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fuzzymonth::{Month, MonthSet};
+    ///
+    /// let set = MonthSet::parse_lenient("jan, xyz, feb");
+    /// assert_eq!(set.len(), 2);
+    /// assert!(set.contains(Month::February));
+    /// ```
+    #[must_use]
+    pub fn parse_lenient(value: &str) -> Self {
+        months(value).into_iter().filter_map(Result::ok).collect()
+    }
+}
+
+/// The parsed parts of a list of months, in order
+///
+/// Each part between commas and `and` is parsed word by word, and as a whole
+/// if some word is not a month on its own.
+fn months(value: &str) -> Vec<Result<Month, ValidationError>> {
+    let mut months = Vec::new();
+    for part in value.split(',') {
+        let words: Vec<&str> = part.split_whitespace().collect();
+        for words in words
+            .split(|word| word.eq_ignore_ascii_case("and"))
+            .filter(|words| !words.is_empty())
+        {
+            let each: Vec<_> = words.iter().map(parse_month).collect();
+            if each.iter().all(Result::is_ok) {
+                months.extend(each);
+                continue;
+            }
+            match parse_month(words.join(" ")) {
+                Ok(month) => months.push(Ok(month)),
+                Err(_) => months.extend(each),
+            }
+        }
+    }
+    months
+}
+
+impl FromIterator<Month> for MonthSet {
+    fn from_iter<I: IntoIterator<Item = Month>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Month> for MonthSet {
+    fn extend<I: IntoIterator<Item = Month>>(&mut self, iter: I) {
+        for month in iter {
+            self.insert(month);
+        }
+    }
+}
+
+impl IntoIterator for MonthSet {
+    type Item = Month;
+    type IntoIter = MonthSetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the months of a [`MonthSet`] in calendar order
+#[derive(Debug, Clone)]
+pub struct MonthSetIter {
+    bits: u16,
+}
+
+impl Iterator for MonthSetIter {
+    type Item = Month;
+
+    fn next(&mut self) -> Option<Month> {
+        if self.bits == 0 {
+            return None;
+        }
+        let index = self.bits.trailing_zeros();
+        self.bits &= self.bits - 1;
+        month_from_number(index + 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = MonthSet { bits: self.bits }.len();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for MonthSetIter {}
+
+impl FusedIterator for MonthSetIter {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_insert_and_remove() {
+        let mut set = MonthSet::new();
+        assert!(set.is_empty());
+        assert!(set.insert(Month::March));
+        assert!(!set.insert(Month::March));
+        assert!(set.insert(Month::January));
+        assert_eq!(set.len(), 2);
+
+        assert!(set.remove(Month::March));
+        assert!(!set.remove(Month::March));
+        assert_eq!(set.len(), 1);
+        assert!(set.contains(Month::January));
+        assert!(!set.contains(Month::March));
+    }
+
+    #[test]
+    fn test_contains() {
+        let set: MonthSet = [Month::January, Month::December].into_iter().collect();
+        assert!(set.contains(Month::January));
+        assert!(set.contains(Month::December));
+        assert!(!set.contains(Month::June));
+        assert!(MonthSet::all().contains(Month::June));
+        assert!(!MonthSet::new().contains(Month::June));
+    }
+
+    #[test]
+    fn test_iter_is_chronological() {
+        let set: MonthSet = [Month::December, Month::March, Month::January, Month::March]
+            .into_iter()
+            .collect();
+        let months: Vec<Month> = set.iter().collect();
+        assert_eq!(months, [Month::January, Month::March, Month::December]);
+        assert_eq!(set.iter().len(), 3);
+
+        let all: Vec<u8> = MonthSet::all().into_iter().map(Month::number).collect();
+        assert_eq!(all, (1..=12).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_parse() {
+        let set = MonthSet::parse("January, March and December").unwrap();
+        let months: Vec<Month> = set.iter().collect();
+        assert_eq!(months, [Month::January, Month::March, Month::December]);
+
+        let set = MonthSet::parse("jan,mar  dec").unwrap();
+        assert_eq!(set.iter().collect::<Vec<_>>(), months);
+
+        let set = MonthSet::parse("3 4").unwrap();
+        assert_eq!(set.iter().collect::<Vec<_>>(), [Month::March, Month::April]);
+        let set = MonthSet::parse("1 2 3").unwrap();
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            [Month::January, Month::February, Month::March]
+        );
+        let set = MonthSet::parse("5 6, 7").unwrap();
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            [Month::May, Month::June, Month::July]
+        );
+
+        assert_eq!(MonthSet::parse(""), Ok(MonthSet::new()));
+    }
+
+    #[test]
+    fn test_parse_multi_word_names() {
+        let set = MonthSet::parse("the third month, June and the first month").unwrap();
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            [Month::January, Month::March, Month::June]
+        );
+        let set = MonthSet::parse_lenient("the third month, xyz");
+        assert_eq!(set.iter().collect::<Vec<_>>(), [Month::March]);
+    }
+
+    #[cfg(feature = "lang-vi")]
+    #[test]
+    fn test_parse_vietnamese() {
+        let set = MonthSet::parse("tháng mười một, tháng ba and tháng 5").unwrap();
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            [Month::March, Month::May, Month::November]
+        );
+    }
+
+    #[test]
+    fn test_parse_deduplicates() {
+        let set = MonthSet::parse("march, Mar, 3 and march").unwrap();
+        assert_eq!(set.len(), 1);
+        assert!(set.contains(Month::March));
+    }
+
+    #[test]
+    fn test_parse_strict_and_lenient() {
+        assert!(matches!(
+            MonthSet::parse("jan, xyz, feb"),
+            Err(ValidationError::InvalidEnumValue(_))
+        ));
+
+        let set = MonthSet::parse_lenient("jan, xyz, feb");
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            [Month::January, Month::February]
+        );
+        assert!(MonthSet::parse_lenient("xyz").is_empty());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a: MonthSet = [Month::January, Month::February].into_iter().collect();
+        let b: MonthSet = [Month::February, Month::March].into_iter().collect();
+        assert_eq!(
+            a.union(b),
+            [Month::January, Month::February, Month::March]
+                .into_iter()
+                .collect()
+        );
+        let mut february = MonthSet::new();
+        february.insert(Month::February);
+        assert_eq!(a.intersection(b), february);
+        assert!(a.intersection(MonthSet::new()).is_empty());
+        assert_eq!(a.union(MonthSet::all()), MonthSet::all());
+    }
+}
@@ -0,0 +1,300 @@
+//! String similarity used for fuzzy matching.
+//!
+//! The Levenshtein functions are a small, `no_std`-compatible port of the
+//! ones in the `strsim` crate, with identical results. On top of that, the
+//! [`SimilarityMetric`] trait lets [`MonthParser`](crate::MonthParser) use a
+//! different metric, such as the keyboard-aware [`KeyboardDistance`].
+
+use alloc::vec::Vec;
+
+/// Levenshtein distance between two strings, counted in `char`s
+///
+/// This is the minimum number of single-character insertions, deletions and
+/// substitutions needed to turn `a` into `b`.
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b_len = b.chars().count();
+    let mut cache: Vec<usize> = (1..=b_len).collect();
+    let mut result = b_len;
+
+    for (i, a_char) in a.chars().enumerate() {
+        result = i + 1;
+        let mut distance_b = i;
+
+        for (j, b_char) in b.chars().enumerate() {
+            let cost = usize::from(a_char != b_char);
+            let distance_a = distance_b + cost;
+            distance_b = cache[j];
+            result = (result + 1).min(distance_a).min(distance_b + 1);
+            cache[j] = result;
+        }
+    }
+
+    result
+}
+
+/// Levenshtein distance normalized to a similarity between 0.0 and 1.0
+///
+/// 1.0 means the strings are identical; two empty strings are identical, too.
+#[allow(clippy::as_conversions, clippy::cast_precision_loss)] // lengths are far below 2^52
+pub fn normalized_levenshtein(a: &str, b: &str) -> f64 {
+    let max_len = a.chars().count().max(b.chars().count());
+    if max_len == 0 {
+        return 1.0;
+    }
+    1.0 - levenshtein(a, b) as f64 / max_len as f64
+}
+
+/// A measure of how similar an input is to a month name
+///
+/// Implementations return a similarity between 0.0 (nothing in common) and
+/// 1.0 (identical), which is compared against the fuzzy matching threshold.
+/// Both strings are already lowercased. Month names scored as NaN are
+/// skipped, as if they were below the threshold.
+pub trait SimilarityMetric {
+    /// Similarity of `input` to the month name `name`
+    fn similarity(&self, input: &str, name: &str) -> f64;
+
+    /// Upper bound of the similarity between any two strings of the given
+    /// lengths (in `char`s)
+    ///
+    /// Month names whose bound is below the threshold are skipped without
+    /// computing their similarity. The default of 1.0 never skips any.
+    fn max_similarity(&self, input_len: usize, name_len: usize) -> f64 {
+        let _ = (input_len, name_len);
+        1.0
+    }
+}
+
+/// Upper bound of the normalized similarity of edit distances where an
+/// insertion or deletion costs one edit
+///
+/// Every extra character in the longer string needs at least one insertion
+/// or deletion, so the distance is at least the difference in length. The
+/// bound is computed exactly like the similarity itself, so that rounding
+/// can never put it below a similarity that reaches the threshold.
+fn length_bound(input_len: usize, name_len: usize) -> f64 {
+    let max_len = input_len.max(name_len);
+    if max_len == 0 {
+        return 1.0;
+    }
+    1.0 - count_to_f64(input_len.abs_diff(name_len)) / count_to_f64(max_len)
+}
+
+/// Plain normalized Levenshtein similarity, the default metric
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Levenshtein;
+
+impl SimilarityMetric for Levenshtein {
+    fn similarity(&self, input: &str, name: &str) -> f64 {
+        normalized_levenshtein(input, name)
+    }
+
+    fn max_similarity(&self, input_len: usize, name_len: usize) -> f64 {
+        length_bound(input_len, name_len)
+    }
+}
+
+/// The physical arrangement of letter keys on a keyboard
+///
+/// Each row lists the letter keys from left to right, top row first. Keys
+/// are neighbors if they are next to each other in a row, or in adjacent rows
+/// at most one position apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyboardLayout {
+    rows: &'static [&'static str],
+}
+
+impl KeyboardLayout {
+    /// The US/UK QWERTY layout
+    pub const QWERTY: Self = Self::new(&["qwertyuiop", "asdfghjkl", "zxcvbnm"]);
+
+    /// The French AZERTY layout
+    pub const AZERTY: Self = Self::new(&["azertyuiop", "qsdfghjklm", "wxcvbn"]);
+
+    /// The German QWERTZ layout
+    pub const QWERTZ: Self = Self::new(&["qwertzuiop", "asdfghjkl", "yxcvbnm"]);
+
+    /// Create a layout from its rows of (lowercase) letter keys
+    #[must_use]
+    pub const fn new(rows: &'static [&'static str]) -> Self {
+        Self { rows }
+    }
+
+    /// Row and column of a key
+    fn position(&self, key: char) -> Option<(usize, usize)> {
+        self.rows
+            .iter()
+            .enumerate()
+            .find_map(|(row, keys)| keys.chars().position(|k| k == key).map(|col| (row, col)))
+    }
+
+    /// Whether `a` and `b` are different keys next to each other
+    #[must_use]
+    pub fn are_neighbors(&self, a: char, b: char) -> bool {
+        match (self.position(a), self.position(b)) {
+            (Some(a), Some(b)) => a != b && a.0.abs_diff(b.0) <= 1 && a.1.abs_diff(b.1) <= 1,
+            _ => false,
+        }
+    }
+}
+
+impl Default for KeyboardLayout {
+    fn default() -> Self {
+        Self::QWERTY
+    }
+}
+
+/// Levenshtein similarity where hitting a neighboring key is a cheaper typo
+///
+/// Substituting a character with one of its neighbors on the keyboard costs
+/// half an edit; all other edits cost one, as with [`Levenshtein`].
+///
+/// # Examples
+///
+/// ```
+/// use fuzzymonth::{parse_month, KeyboardDistance, KeyboardLayout, Month, MonthParser};
+///
+/// // "t" is next to "y" on the keyboard, "q" is not
+/// assert!(parse_month("mat").is_err());
+///
+/// let parser = MonthParser::new().metric(KeyboardDistance::new(KeyboardLayout::QWERTY));
+/// assert_eq!(parser.parse("mat").unwrap(), Month::May);
+/// assert!(parser.parse("maq").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KeyboardDistance {
+    layout: KeyboardLayout,
+}
+
+impl KeyboardDistance {
+    /// Create the metric for a keyboard layout
+    #[must_use]
+    pub const fn new(layout: KeyboardLayout) -> Self {
+        Self { layout }
+    }
+
+    /// Edit distance with cheaper substitutions of neighboring keys
+    fn distance(&self, a: &str, b: &str) -> f64 {
+        let b: Vec<char> = b.chars().collect();
+        let mut cache: Vec<f64> = (1..=b.len()).map(count_to_f64).collect();
+        let mut result = count_to_f64(b.len());
+
+        for (i, a_char) in a.chars().enumerate() {
+            result = count_to_f64(i + 1);
+            let mut distance_b = count_to_f64(i);
+
+            for (j, &b_char) in b.iter().enumerate() {
+                let cost = if a_char == b_char {
+                    0.0
+                } else if self.layout.are_neighbors(a_char, b_char) {
+                    0.5
+                } else {
+                    1.0
+                };
+                let distance_a = distance_b + cost;
+                distance_b = cache[j];
+                result = (result + 1.0).min(distance_a).min(distance_b + 1.0);
+                cache[j] = result;
+            }
+        }
+
+        result
+    }
+}
+
+impl SimilarityMetric for KeyboardDistance {
+    fn similarity(&self, input: &str, name: &str) -> f64 {
+        let max_len = input.chars().count().max(name.chars().count());
+        if max_len == 0 {
+            return 1.0;
+        }
+        1.0 - self.distance(input, name) / count_to_f64(max_len)
+    }
+
+    fn max_similarity(&self, input_len: usize, name_len: usize) -> f64 {
+        length_bound(input_len, name_len)
+    }
+}
+
+#[allow(clippy::as_conversions, clippy::cast_precision_loss)] // lengths are far below 2^52
+const fn count_to_f64(count: usize) -> f64 {
+    count as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("", "", 0)]
+    #[case("", "abc", 3)]
+    #[case("abc", "", 3)]
+    #[case("march", "march", 0)]
+    #[case("marh", "march", 1)]
+    #[case("kitten", "sitting", 3)]
+    #[case("février", "fevrier", 1)] // counted in chars, not bytes
+    fn test_levenshtein(#[case] a: &str, #[case] b: &str, #[case] expected: usize) {
+        assert_eq!(levenshtein(a, b), expected);
+        assert_eq!(levenshtein(b, a), expected);
+    }
+
+    #[rstest]
+    #[case("", "", 1.0)]
+    #[case("", "abc", 0.0)]
+    #[case("march", "march", 1.0)]
+    #[case("marh", "march", 0.8)]
+    #[case("kitten", "sitting", 1.0 - 3.0 / 7.0)]
+    fn test_normalized_levenshtein(#[case] a: &str, #[case] b: &str, #[case] expected: f64) {
+        assert!((normalized_levenshtein(a, b) - expected).abs() < 1e-10);
+        assert!((Levenshtein.similarity(a, b) - expected).abs() < 1e-10);
+    }
+
+    #[rstest]
+    #[case("", "")]
+    #[case("", "may")]
+    #[case("marchh", "march")]
+    #[case("ju", "june")]
+    #[case("kitten", "sitting")]
+    #[case("sepetember", "september")]
+    fn test_max_similarity_is_an_upper_bound(#[case] a: &str, #[case] b: &str) {
+        let (a_len, b_len) = (a.chars().count(), b.chars().count());
+        assert!(Levenshtein.similarity(a, b) <= Levenshtein.max_similarity(a_len, b_len));
+        let keyboard = KeyboardDistance::default();
+        assert!(keyboard.similarity(a, b) <= keyboard.max_similarity(a_len, b_len));
+    }
+
+    #[rstest]
+    #[case(KeyboardLayout::QWERTY, 'u', 'i', true)]
+    #[case(KeyboardLayout::QWERTY, 'v', 'c', true)]
+    #[case(KeyboardLayout::QWERTY, 'a', 'w', true)]
+    #[case(KeyboardLayout::QWERTY, 'y', 'q', false)]
+    #[case(KeyboardLayout::QWERTY, 'a', 'a', false)]
+    #[case(KeyboardLayout::QWERTY, 'a', '1', false)]
+    #[case(KeyboardLayout::AZERTY, 'a', 'w', false)]
+    #[case(KeyboardLayout::AZERTY, 'm', 'l', true)]
+    #[case(KeyboardLayout::QWERTZ, 'z', 'u', true)]
+    fn test_are_neighbors(
+        #[case] layout: KeyboardLayout,
+        #[case] a: char,
+        #[case] b: char,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(layout.are_neighbors(a, b), expected);
+        assert_eq!(layout.are_neighbors(b, a), expected);
+    }
+
+    #[rstest]
+    #[case("", "", 1.0)]
+    #[case("march", "march", 1.0)]
+    #[case("marh", "march", 0.8)]
+    #[case("janiary", "january", 1.0 - 0.5 / 7.0)]
+    #[case("nocember", "november", 1.0 - 0.5 / 8.0)]
+    #[case("nozember", "november", 1.0 - 1.0 / 8.0)]
+    fn test_keyboard_distance(#[case] a: &str, #[case] b: &str, #[case] expected: f64) {
+        let metric = KeyboardDistance::new(KeyboardLayout::QWERTY);
+        assert!((metric.similarity(a, b) - expected).abs() < 1e-10);
+        assert!((metric.similarity(b, a) - expected).abs() < 1e-10);
+    }
+}
@@ -0,0 +1,56 @@
+//! WebAssembly bindings for calling the parser from JavaScript.
+
+use alloc::{format, string::String};
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{parse_month, parse_month_with_score};
+
+/// Parse a month and return its number (1 to 12), or `undefined` if the
+/// input is not a valid month
+#[wasm_bindgen]
+#[must_use]
+pub fn parse_month_js(value: &str) -> Option<u8> {
+    parse_month(value).ok().map(crate::Month::number)
+}
+
+/// Parse a month and return a JSON string with its name, number and the
+/// confidence of the match, or `undefined` if the input is not a valid month
+///
+/// ```json
+/// {"month":"September","number":9,"confidence":1}
+/// ```
+#[wasm_bindgen]
+#[must_use]
+pub fn parse_month_with_score_js(value: &str) -> Option<String> {
+    let (month, score) = parse_month_with_score(value).ok()?;
+    Some(format!(
+        r#"{{"month":"{}","number":{},"confidence":{score}}}"#,
+        month.name(),
+        month.number()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_month_js() {
+        assert_eq!(parse_month_js("sept"), Some(9));
+        assert_eq!(parse_month_js("xyz"), None);
+    }
+
+    #[test]
+    fn test_parse_month_with_score_js() {
+        assert_eq!(
+            parse_month_with_score_js("sept").as_deref(),
+            Some(r#"{"month":"September","number":9,"confidence":1}"#)
+        );
+        assert_eq!(
+            parse_month_with_score_js("marh").as_deref(),
+            Some(r#"{"month":"March","number":3,"confidence":0.8}"#)
+        );
+        assert_eq!(parse_month_with_score_js("xyz"), None);
+    }
+}
@@ -0,0 +1,172 @@
+//! Integration tests for the `fuzzymonth` command-line tool.
+
+#![cfg(feature = "std")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn fuzzymonth(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_fuzzymonth"))
+        .args(args)
+        .output()
+        .expect("failed to run fuzzymonth")
+}
+
+fn fuzzymonth_with_stdin(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_fuzzymonth"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to run fuzzymonth");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn parses_arguments() {
+    let output = fuzzymonth(&["march", "feb", "12"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].contains("March (3)"));
+    assert!(lines[1].contains("February (2)"));
+    assert!(lines[2].contains("December (12)"));
+    assert!(!stdout.contains("Fuzzy Month Parser"));
+}
+
+#[test]
+fn fails_if_any_argument_is_invalid() {
+    let output = fuzzymonth(&["march", "feb", "13"]);
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[2].contains("Invalid input: 13"));
+}
+
+#[test]
+fn prints_json_array_for_arguments() {
+    let output = fuzzymonth(&["--json", "sept", "xyz"]);
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(
+        stdout.trim_end(),
+        concat!(
+            r#"[{"input":"sept","success":true,"month":"September","number":9,"confidence":1,"exact":true},"#,
+            r#"{"input":"xyz","success":false,"month":null,"number":null,"confidence":null,"exact":null}]"#
+        )
+    );
+}
+
+#[test]
+fn marks_fuzzy_matches_in_json() {
+    let output = fuzzymonth(&["--json", "janurary"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(
+        stdout.trim_end(),
+        r#"[{"input":"janurary","success":true,"month":"January","number":1,"confidence":0.875,"exact":false}]"#
+    );
+}
+
+#[test]
+fn escapes_json_strings() {
+    let output = fuzzymonth(&["--json", r#"a"b\c"#]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains(r#""input":"a\"b\\c""#));
+}
+
+#[test]
+fn prints_json_lines_for_stdin() {
+    let output = fuzzymonth_with_stdin(&["--json"], "sept\nxyz\n");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(
+        lines,
+        [
+            r#"{"input":"sept","success":true,"month":"September","number":9,"confidence":1,"exact":true}"#,
+            r#"{"input":"xyz","success":false,"month":null,"number":null,"confidence":null,"exact":null}"#,
+        ]
+    );
+}
+
+#[test]
+fn parses_piped_lines() {
+    let output = fuzzymonth_with_stdin(&[], "march\n\nxyz\n  Dec. \nseptembr");
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(
+        stdout.lines().collect::<Vec<_>>(),
+        [
+            "✓ March (3)",
+            "✗ Invalid input: ",
+            "✗ Invalid input: xyz",
+            "✓ December (12)",
+            "✓ September (9)",
+        ]
+    );
+}
+
+#[test]
+fn succeeds_if_all_piped_lines_are_valid() {
+    let output = fuzzymonth_with_stdin(&[], "jan\nfeb\n");
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "✓ January (1)\n✓ February (2)\n"
+    );
+}
+
+#[test]
+fn threshold_changes_fuzzy_strictness() {
+    assert!(!fuzzymonth(&["janry"]).status.success());
+    assert!(!fuzzymonth(&["--threshold", "0.75", "janry"])
+        .status
+        .success());
+
+    let output = fuzzymonth(&["--threshold", "0.6", "janry"]);
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stdout)
+        .unwrap()
+        .contains("January (1)"));
+}
+
+#[test]
+fn rejects_invalid_thresholds() {
+    for args in [
+        &["--threshold", "1.5", "jan"][..],
+        &["--threshold", "-0.1", "jan"],
+        &["--threshold", "strict", "jan"],
+        &["--threshold"],
+    ] {
+        let output = fuzzymonth(args);
+        assert_eq!(output.status.code(), Some(2));
+        assert!(output.stdout.is_empty());
+        assert!(String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("threshold"));
+    }
+}
+
+#[test]
+fn no_color_disables_escape_sequences() {
+    let output = Command::new(env!("CARGO_BIN_EXE_fuzzymonth"))
+        .args(["march", "xyz"])
+        .env("NO_COLOR", "1")
+        .output()
+        .unwrap();
+    assert!(!output.stdout.contains(&0x1b));
+
+    let output = fuzzymonth(&["--no-color", "march", "xyz"]);
+    assert!(!output.stdout.contains(&0x1b));
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "✓ March (3)\n✗ Invalid input: xyz\n"
+    );
+}
@@ -0,0 +1,18 @@
+//! Tests for the WebAssembly bindings, run with `wasm-pack test --node -- --features wasm`.
+
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use fuzzymonth::{parse_month_js, parse_month_with_score_js};
+
+#[wasm_bindgen_test]
+fn parses_sept() {
+    assert_eq!(parse_month_js("sept"), Some(9));
+}
+
+#[wasm_bindgen_test]
+fn rejects_invalid_input() {
+    assert_eq!(parse_month_js("xyz"), None);
+    assert_eq!(parse_month_with_score_js("xyz"), None);
+}